@@ -0,0 +1,76 @@
+// compares definition lookup through a linear `ArrayMap` scan with the
+// `Trie` index used by `State`, run with `cargo bench --bench dispatch`
+use lex::definition::Definition;
+use lex::grammar::token::Token;
+use lex::state::State;
+use lex::utils::arraymap::ArrayMap;
+use lex::utils::trie::Trie;
+use std::hint::black_box;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+const LOOKUPS: usize = 2_000;
+
+// `(nI .to nJ)` as declared by `(@dec (nI .to nJ))`
+fn edge(from: usize, to: usize) -> Token {
+    Token::List(vec![
+        Token::Atom(format!("n{}", from)),
+        Token::Identifier("to".to_owned()),
+        Token::Atom(format!("n{}", to)),
+    ])
+}
+
+fn fact(sig: Token) -> Definition {
+    Definition {
+        inp_sig: sig.clone(),
+        out_sig: Token::_true(),
+        res_sig: sig,
+        func: Rc::new(Box::new(|_: &mut State, _: Token| Ok(Token::_true()))),
+    }
+}
+
+fn time<F: FnMut(usize) -> usize>(mut lookup: F) -> Duration {
+    let start = Instant::now();
+    let mut found = 0;
+    for i in 0..LOOKUPS {
+        found += lookup(i);
+    }
+    black_box(found);
+
+    start.elapsed() / LOOKUPS as u32
+}
+
+fn main() {
+    println!(
+        "{:>8} {:>14} {:>14} {:>10}",
+        "facts", "arraymap", "trie", "speedup"
+    );
+
+    for size in [100, 1_000, 10_000, 50_000] {
+        let mut linear = ArrayMap::new();
+        let mut trie = Trie::new();
+        for i in 0..size {
+            let sig = edge(i, (i * 7 + 1) % size);
+            linear.push(sig.clone(), fact(sig.clone()));
+            trie.push(sig.clone(), fact(sig));
+        }
+
+        let calls: Vec<Token> = (0..LOOKUPS)
+            .map(|i| {
+                let from = (i * 31) % size;
+                edge(from, (from * 7 + 1) % size)
+            })
+            .collect();
+
+        let a = time(|i| linear.search(calls[i].clone()).len());
+        let b = time(|i| trie.search(&calls[i]).len());
+
+        println!(
+            "{:>8} {:>14?} {:>14?} {:>9.1}x",
+            size,
+            a,
+            b,
+            a.as_secs_f64() / b.as_secs_f64()
+        );
+    }
+}
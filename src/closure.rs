@@ -0,0 +1,51 @@
+use crate::error::LexError;
+use crate::grammar::token::Token;
+use crate::state::State;
+use crate::utils::unify;
+
+// anonymous function made by `@fn`, its body runs in a child of the scope
+// it was made in, whatever scope it is called from
+#[derive(Clone)]
+pub struct Closure {
+    pub params: Vec<Token>,
+    pub body: Token,
+    pub state: State,
+}
+
+impl Closure {
+    pub fn new(params: Vec<Token>, body: Token, state: State) -> Self {
+        Self {
+            params,
+            body,
+            state,
+        }
+    }
+
+    // run the body with the parameters matched against `args`
+    pub fn apply(&self, call: &Token, args: Vec<Token>) -> Result<Token, LexError> {
+        if args.len() != self.params.len() {
+            return Err(LexError::Arity {
+                expected: self.params.len(),
+                found: args.len(),
+                call: call.clone(),
+            });
+        }
+
+        let params = Token::List(self.params.clone());
+        let args = Token::List(args);
+        let bindings = match unify::matches(&params, &args) {
+            Some(bindings) => bindings,
+            None => return Err(LexError::NoMatch(args.unspanned())),
+        };
+
+        self.state.child().exec(bindings.resolve(self.body.clone()))
+    }
+}
+
+// the captured scope is left out, it may well contain the closure itself
+impl std::fmt::Debug for Closure {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", Token::List(self.params.clone()))?;
+        write!(f, " -> {}", self.body)
+    }
+}
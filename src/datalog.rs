@@ -0,0 +1,362 @@
+use crate::error::LexError;
+use crate::grammar::token::Token;
+use crate::state::State;
+use crate::utils::trie::Key;
+use crate::utils::unify::{self, Substitution};
+use std::collections::{HashMap, HashSet};
+
+// a relation is identified by the length of its lists and the identifiers
+// and operators at fixed positions, e.g. `(X .to Y)`, lists without any of
+// them are prefix calls identified by their constant head, e.g. `(free X)`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Relation(Vec<Option<Key>>);
+
+impl Relation {
+    pub fn of(token: &Token) -> Option<Self> {
+        let list = match token.inner() {
+            Token::List(list) => list,
+            _ => return None,
+        };
+
+        let mut keys: Vec<Option<Key>> = list
+            .iter()
+            .map(|token| match token.inner() {
+                Token::Identifier(_)
+                | Token::Operator(_)
+                | Token::Keyword(_)
+                | Token::KeywordArg(_, _) => Some(Key::of(token)),
+                _ => None,
+            })
+            .collect();
+
+        if keys.iter().all(Option::is_none) {
+            if let Some(head) = list.first().filter(|head| {
+                matches!(
+                    head.inner(),
+                    Token::Atom(_)
+                        | Token::String(_)
+                        | Token::Integer(_)
+                        | Token::Number(_)
+                        | Token::Boolean(_)
+                )
+            }) {
+                keys[0] = Some(Key::of(head));
+            }
+        }
+
+        Some(Relation(keys))
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Literal {
+    Positive(Token),
+    Negative(Token),
+}
+
+// a single conjunction of literals, rules with `@or` in their body are
+// split into one rule per alternative
+#[derive(Clone, Debug)]
+struct Rule {
+    head: Token,
+    relation: Relation,
+    body: Vec<Literal>,
+}
+
+// every alternative of `body`, each one a conjunction of literals
+fn disjuncts(body: &Token) -> Vec<Vec<Literal>> {
+    let list = match body.inner() {
+        Token::Boolean(true) => return vec![vec![]],
+        Token::Boolean(false) => return vec![],
+        Token::List(list) => list,
+        _ => return vec![vec![Literal::Positive(body.clone())]],
+    };
+
+    match (list.first().map(Token::inner), list.len()) {
+        (Some(Token::Keyword(key)), _) if key == "and" => {
+            list[1..].iter().fold(vec![vec![]], |res, operand| {
+                let mut product = vec![];
+                for prefix in res.iter() {
+                    for alternative in disjuncts(operand) {
+                        let mut conjunction = prefix.clone();
+                        conjunction.extend(alternative);
+                        product.push(conjunction);
+                    }
+                }
+
+                product
+            })
+        }
+        (Some(Token::Keyword(key)), _) if key == "or" => {
+            list[1..].iter().flat_map(disjuncts).collect()
+        }
+        (Some(Token::Operator(op)), 2) if op == "!" => {
+            vec![vec![Literal::Negative(list[1].clone())]]
+        }
+        (Some(Token::Operator(op)), 2) if op == "?" => disjuncts(&list[1]),
+        _ => vec![vec![Literal::Positive(body.clone())]],
+    }
+}
+
+// facts derived for every relation of a program
+#[derive(Debug, Default)]
+pub struct Database {
+    facts: HashMap<Relation, Vec<Token>>,
+    seen: HashSet<String>,
+}
+
+impl Database {
+    // facts of the relation `goal` belongs to, none if it is not part of
+    // the program
+    pub fn get(&self, goal: &Token) -> Option<&[Token]> {
+        let relation = Relation::of(goal)?;
+        self.facts.get(&relation).map(Vec::as_slice)
+    }
+
+    pub fn len(&self) -> usize {
+        self.seen.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.seen.is_empty()
+    }
+
+    fn insert(&mut self, relation: &Relation, fact: Token) -> bool {
+        // tokens are not hashable, their debug form is unambiguous
+        if !self.seen.insert(format!("{:?}", fact)) {
+            return false;
+        }
+
+        self.facts.entry(relation.clone()).or_default().push(fact);
+        true
+    }
+}
+
+// bottom up evaluation of the `@dec` facts and `@def` rules visible from a
+// state, rules are evaluated semi-naively one stratum at a time so recursive
+// relations reach a fixpoint and negated relations are complete before use
+pub struct Datalog {
+    state: State,
+    rules: Vec<Rule>,
+    strata: HashMap<Relation, usize>,
+}
+
+impl Datalog {
+    pub fn new(state: &State) -> Result<Self, LexError> {
+        let mut rules = vec![];
+        for clause in state.clauses() {
+            let relation = match Relation::of(&clause.inp_sig) {
+                Some(relation) => relation,
+                None => continue,
+            };
+
+            for body in disjuncts(&clause.out_sig) {
+                rules.push(Rule {
+                    head: clause.inp_sig.clone(),
+                    relation: relation.clone(),
+                    body,
+                });
+            }
+        }
+
+        let strata = Self::stratify(&rules)?;
+
+        Ok(Self {
+            state: state.clone(),
+            rules,
+            strata,
+        })
+    }
+
+    // a rule is in a stratum at least as high as the relations it uses, and
+    // strictly higher than the ones it negates
+    fn stratify(rules: &[Rule]) -> Result<HashMap<Relation, usize>, LexError> {
+        let mut strata: HashMap<Relation, usize> = rules
+            .iter()
+            .map(|rule| (rule.relation.clone(), 0))
+            .collect();
+        let limit = strata.len();
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+
+            for rule in rules {
+                for literal in rule.body.iter() {
+                    let (token, offset) = match literal {
+                        Literal::Positive(token) => (token, 0),
+                        Literal::Negative(token) => (token, 1),
+                    };
+
+                    let required = match Relation::of(token).and_then(|r| strata.get(&r)) {
+                        Some(stratum) => stratum + offset,
+                        None => continue,
+                    };
+
+                    if strata[&rule.relation] < required {
+                        if required > limit {
+                            return Err(LexError::Unstratified(rule.head.clone()));
+                        }
+
+                        strata.insert(rule.relation.clone(), required);
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        Ok(strata)
+    }
+
+    pub fn run(&self) -> Result<Database, LexError> {
+        let mut db = Database::default();
+        for relation in self.strata.keys() {
+            db.facts.insert(relation.clone(), vec![]);
+        }
+
+        let top = self.strata.values().copied().max().unwrap_or(0);
+        for stratum in 0..=top {
+            let rules: Vec<&Rule> = self
+                .rules
+                .iter()
+                .filter(|rule| self.strata[&rule.relation] == stratum)
+                .collect();
+
+            // first round over everything known so far
+            let mut found = vec![];
+            for rule in rules.iter() {
+                self.fire(rule, None, &db, &db, &mut found)?;
+            }
+            let mut delta = Self::merge(&mut db, found);
+
+            // later rounds only join with facts new in the previous round
+            while !delta.is_empty() {
+                let mut found = vec![];
+                for rule in rules.iter() {
+                    for (i, literal) in rule.body.iter().enumerate() {
+                        if let Literal::Positive(token) = literal {
+                            if delta.get(token).is_some_and(|facts| !facts.is_empty()) {
+                                self.fire(rule, Some(i), &db, &delta, &mut found)?;
+                            }
+                        }
+                    }
+                }
+
+                delta = Self::merge(&mut db, found);
+            }
+        }
+
+        Ok(db)
+    }
+
+    // add `found` to `db`, returns the facts that were not known yet
+    fn merge(db: &mut Database, found: Vec<(Relation, Token)>) -> Database {
+        let mut delta = Database::default();
+        for (relation, fact) in found {
+            if db.insert(&relation, fact.clone()) {
+                delta.insert(&relation, fact);
+            }
+        }
+
+        delta
+    }
+
+    // derive the heads of `rule`, the literal at `pivot` is joined with
+    // `delta` and every other one with `total`
+    fn fire(
+        &self,
+        rule: &Rule,
+        pivot: Option<usize>,
+        total: &Database,
+        delta: &Database,
+        found: &mut Vec<(Relation, Token)>,
+    ) -> Result<(), LexError> {
+        let mut res = vec![];
+        self.join(rule, 0, pivot, Substitution::new(), total, delta, &mut res)?;
+
+        for subst in res {
+            let head = subst.resolve(rule.head.clone()).unspanned();
+            if !unify::variables(&head).is_empty() {
+                return Err(LexError::Unsafe(rule.head.clone().unspanned()));
+            }
+
+            found.push((rule.relation.clone(), head));
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn join(
+        &self,
+        rule: &Rule,
+        at: usize,
+        pivot: Option<usize>,
+        subst: Substitution,
+        total: &Database,
+        delta: &Database,
+        res: &mut Vec<Substitution>,
+    ) -> Result<(), LexError> {
+        let literal = match rule.body.get(at) {
+            Some(literal) => literal,
+            None => {
+                res.push(subst);
+                return Ok(());
+            }
+        };
+
+        match literal {
+            Literal::Positive(token) => {
+                let goal = subst.resolve(token.clone());
+                if let Some((a, b)) = unify::equation(&goal) {
+                    let mut next = subst.clone();
+                    if next.unify(a, b) {
+                        self.join(rule, at + 1, pivot, next, total, delta, res)?;
+                    }
+
+                    return Ok(());
+                }
+                let source = if pivot == Some(at) { delta } else { total };
+
+                match source.get(&goal) {
+                    Some(facts) => {
+                        for fact in facts {
+                            let mut next = subst.clone();
+                            if next.unify(&goal, fact) {
+                                self.join(rule, at + 1, pivot, next, total, delta, res)?;
+                            }
+                        }
+                    }
+                    None if self.holds(goal)? => {
+                        self.join(rule, at + 1, pivot, subst, total, delta, res)?
+                    }
+                    None => (),
+                }
+            }
+            Literal::Negative(token) => {
+                let goal = subst.resolve(token.clone());
+
+                let holds = match total.get(&goal) {
+                    Some(facts) => facts.iter().any(|fact| subst.clone().unify(&goal, fact)),
+                    None => self.holds(goal)?,
+                };
+
+                if !holds {
+                    self.join(rule, at + 1, pivot, subst, total, delta, res)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // literals outside of the program are evaluated, e.g. comparisons
+    fn holds(&self, goal: Token) -> Result<bool, LexError> {
+        if self.state.find_all(goal.clone()).is_empty() {
+            return Ok(false);
+        }
+
+        let res = self.state.clone().exec(goal)?;
+        Ok(!matches!(res.inner(), Token::Boolean(false)))
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+use super::{
+    datalog::Datalog,
+    error::LexError,
+    evaluator::Engine,
+    grammar::token::Token,
+    test_support::{atoms, datalog, sorted_answers},
+};
+
+#[cfg(test)]
+const GRAPH: &str = r#"
+@document graph
+(@dec (a .to b))
+(@dec (b .to c))
+(@dec (c .to a))
+(@dec (c .to d))
+(@dec (e .to e))
+(@dec (a .node))
+(@dec (d .node))
+(@dec (e .node))
+(@dec (f .node))
+(@def (X .path Y) (X .to Y))
+(@def (X .path Z) (@and (X .path Y) (Y .to Z)))
+(@def (X .unreachable Y) (@and (X .node) (Y .node) (! (X .path Y))))
+"#;
+
+#[test]
+fn should_terminate_on_left_recursive_cycles() {
+    let res = sorted_answers(&mut datalog(GRAPH), "(a .path X)", "X");
+
+    assert_eq!(atoms(&["a", "b", "c", "d"]), res)
+}
+
+#[test]
+fn should_evaluate_stratified_negation() {
+    let res = sorted_answers(&mut datalog(GRAPH), "(a .unreachable Y)", "Y");
+
+    assert_eq!(atoms(&["e", "f"]), res)
+}
+
+#[test]
+fn should_stratify_atom_headed_relations() {
+    let source = r#"
+@document free
+(@dec (node a))
+(@dec (node b))
+(@dec (marked a))
+(@def (free X) (@and (node X) (! (marked X))))
+"#;
+    let res = sorted_answers(&mut datalog(source), "(free W)", "W");
+
+    assert_eq!(atoms(&["b"]), res)
+}
+
+#[test]
+fn should_answer_query_operator_from_relations() {
+    let mut engine = datalog(GRAPH);
+
+    let res = engine.parse_line("(? (e .path e))").unwrap();
+    assert_eq!(Token::_true(), res);
+
+    let res = engine.parse_line("(! (d .path a))").unwrap();
+    assert_eq!(Token::_true(), res);
+}
+
+#[test]
+fn should_materialize_every_fact_once() {
+    let mut engine = Engine::new();
+    engine.parse(GRAPH).unwrap();
+
+    let db = Datalog::new(engine.state()).unwrap().run().unwrap();
+
+    // 5 edges, 4 nodes, 13 paths and 13 unreachable pairs
+    assert_eq!(35, db.len())
+}
+
+#[test]
+fn error_on_negative_cycle() {
+    let source = "@document loop (@dec (a .node)) (@def (X .odd) (@and (X .node) (! (X .odd))))";
+    let mut engine = datalog(source);
+
+    let res = engine.query("(a .odd)").map_err(LexError::unspanned);
+
+    assert!(matches!(res, Err(LexError::Unstratified(_))))
+}
+
+#[test]
+fn error_on_unsafe_rule() {
+    let source = "@document unsafe (@dec (a .node)) (@def (X .twin Y) (X .node))";
+    let mut engine = datalog(source);
+
+    let res = engine.query("(a .twin Y)");
+
+    assert!(matches!(res, Err(LexError::Unsafe(_))))
+}
+
+#[test]
+fn should_unify_equations_in_rules() {
+    let source = "@document eq (@dec (a .node)) (@dec (b .node)) (@def (X .self Y) (@and (X .node) (= X Y)))";
+    let mut engine = datalog(source);
+
+    let res = sorted_answers(&mut engine, "(X .self b)", "X");
+
+    assert_eq!(atoms(&["b"]), res)
+}
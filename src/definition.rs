@@ -1,24 +1,47 @@
+use crate::error::LexError;
 use crate::grammar::token::Token;
 use crate::handler::Handler;
 use crate::state::State;
-use crate::utils::variables;
-use std::sync::Arc;
+use crate::utils::unify::{self, Substitution};
+use std::rc::Rc;
 
 #[derive(Clone)]
 pub struct Definition {
     pub inp_sig: Token,
     pub out_sig: Token,
     pub res_sig: Token,
-    pub func: Arc<Box<Handler>>,
+    pub func: Rc<Box<Handler>>,
 }
 
 impl Definition {
-    pub fn handle(&self, state: &mut State, inp: Token) -> Token {
-        let arg = variables::fill_variable(inp.clone(), self.inp_sig.clone());
+    // facts and rules declared with `@dec` and `@def` carry their body as
+    // output signature, builtins only produce a `Token::Value`
+    pub fn is_clause(&self) -> bool {
+        !matches!(self.out_sig.inner(), Token::Value)
+    }
+
+    // bindings of the input signature's variables if `inp` unifies with it
+    pub fn matches(&self, inp: &Token) -> Option<Substitution> {
+        unify::matches(&self.inp_sig, inp)
+    }
+
+    pub fn handle(&self, state: &mut State, inp: Token) -> Result<Token, LexError> {
+        match self.matches(&inp) {
+            Some(bindings) => self.call(state, inp, bindings),
+            None => Err(LexError::Unresolved(inp)),
+        }
+    }
+
+    // run the handler with `bindings` visible through `State::bindings`
+    pub fn call(
+        &self,
+        state: &mut State,
+        inp: Token,
+        bindings: Substitution,
+    ) -> Result<Token, LexError> {
         let func = &self.func;
 
-        let out = func(state, arg);
-        out
+        func(&mut state.with_bindings(bindings), inp)
     }
 }
 
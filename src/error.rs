@@ -0,0 +1,213 @@
+use crate::grammar::error::ParseError;
+use crate::grammar::span::Span;
+use crate::grammar::token::Token;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LexError {
+    // source could not be parsed
+    Parse {
+        offset: usize,
+        message: String,
+    },
+    // no definition matches the call
+    Unresolved(Token),
+    // operands are not of a type the operation handles
+    TypeMismatch {
+        expected: String,
+        found: Token,
+    },
+    // no arm of a `@match` matches the value
+    NoMatch(Token),
+    // malformed format string or missing format argument
+    Format(String),
+    // index past the end of a list or string
+    OutOfRange(Token),
+    // integer division of the call by zero
+    DivisionByZero(Token),
+    // definition exists but with a different number of arguments
+    Arity {
+        expected: usize,
+        found: usize,
+        call: Token,
+    },
+    // rule depends negatively on its own relation
+    Unstratified(Token),
+    // rule head has variables its body does not bind
+    Unsafe(Token),
+    // `.exit` called with a status code, ends the program
+    Exit(i32),
+    // module needs a capability the engine does not allow
+    Denied(String),
+    Import {
+        path: String,
+        reason: Box<LexError>,
+    },
+    Io {
+        path: String,
+        message: String,
+    },
+    // error raised while evaluating the token at `Span`
+    Spanned(Span, Box<LexError>),
+    // every problem found in a single pass, e.g. by the parser
+    Many(Vec<LexError>),
+}
+
+impl LexError {
+    pub fn parse(source: &str, err: ParseError) -> Self {
+        LexError::Parse {
+            offset: err.offset(source),
+            message: err.message,
+        }
+    }
+
+    pub fn parse_all(source: &str, errors: Vec<ParseError>) -> Self {
+        let mut errors: Vec<LexError> = errors
+            .into_iter()
+            .map(|err| LexError::parse(source, err))
+            .collect();
+
+        match errors.len() {
+            1 => errors.remove(0),
+            _ => LexError::Many(errors),
+        }
+    }
+
+    pub fn io(path: &str, err: std::io::Error) -> Self {
+        LexError::Io {
+            path: path.to_owned(),
+            message: err.to_string(),
+        }
+    }
+
+    // attach a position unless the error already has a more precise one
+    pub fn at(self, span: Span) -> Self {
+        match self {
+            LexError::Spanned(_, _) => self,
+            err => LexError::Spanned(span, Box::new(err)),
+        }
+    }
+
+    // the error without its position
+    pub fn unspanned(self) -> Self {
+        match self {
+            LexError::Spanned(_, err) => err.unspanned(),
+            err => err,
+        }
+    }
+
+    // status code if the error is a requested exit, possibly from an
+    // included file
+    pub fn exit_code(&self) -> Option<i32> {
+        match self {
+            LexError::Exit(code) => Some(*code),
+            LexError::Spanned(_, err) => err.exit_code(),
+            LexError::Import { reason, .. } => reason.exit_code(),
+            _ => None,
+        }
+    }
+
+    pub fn span(&self, source: &str) -> Option<Span> {
+        match self {
+            LexError::Spanned(span, _) => Some(*span),
+            LexError::Parse { offset, .. } => Some(Span::new(source, *offset, *offset + 1)),
+            _ => None,
+        }
+    }
+
+    // rustc-style report pointing at the offending part of `source`
+    pub fn render(&self, file: &str, source: &str) -> String {
+        if let LexError::Many(errors) = self {
+            let rendered: Vec<String> = errors.iter().map(|e| e.render(file, source)).collect();
+            return rendered.join("\n\n");
+        }
+
+        let err = match self {
+            LexError::Spanned(_, err) => err.as_ref(),
+            err => err,
+        };
+
+        let mut out = match err {
+            LexError::Import { path, .. } => format!("error: failed to import \"{}\"", path),
+            err => format!("error: {}", err),
+        };
+
+        // spans of other files, e.g. the body of an included definition,
+        // can not be shown against `source`
+        if let Some((span, text)) = self
+            .span(source)
+            .and_then(|span| Some((span, span.text(source)?)))
+        {
+            let line = span.source_line(source);
+            let number = span.line.to_string();
+            let pad = " ".repeat(number.len());
+
+            let width = line.chars().count().saturating_sub(span.column - 1);
+            let underline = "^".repeat(text.chars().count().clamp(1, width.max(1)));
+
+            out += &format!("\n{}--> {}:{}:{}", pad, file, span.line, span.column);
+            out += &format!("\n{} |", pad);
+            out += &format!("\n{} | {}", number, line);
+            out += &format!("\n{} | {}{}", pad, " ".repeat(span.column - 1), underline);
+        }
+
+        if let LexError::Import { path, reason } = err {
+            let source = std::fs::read_to_string(path).unwrap_or_default();
+            out += "\n";
+            out += &reason.render(path.as_str(), &source);
+        }
+
+        out
+    }
+}
+
+impl std::fmt::Display for LexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            LexError::Parse { message, .. } => write!(f, "parse error: {}", message),
+            LexError::Unresolved(call) => write!(f, "no definition matches {}", call),
+            LexError::TypeMismatch { expected, found } => {
+                write!(f, "type mismatch: expected {}, found {}", expected, found)
+            }
+            LexError::NoMatch(value) => write!(f, "no pattern matches {}", value),
+            LexError::Format(message) => write!(f, "invalid format: {}", message),
+            LexError::OutOfRange(call) => write!(f, "index out of range in {}", call),
+            LexError::DivisionByZero(call) => write!(f, "division by zero in {}", call),
+            LexError::Arity {
+                expected,
+                found,
+                call,
+            } => write!(
+                f,
+                "arity mismatch: expected {} argument(s), found {} in {}",
+                expected, found, call
+            ),
+            LexError::Unstratified(rule) => {
+                write!(
+                    f,
+                    "cannot stratify {}: it depends negatively on itself",
+                    rule
+                )
+            }
+            LexError::Unsafe(rule) => {
+                write!(
+                    f,
+                    "unsafe rule {}: its body does not bind every variable",
+                    rule
+                )
+            }
+            LexError::Exit(code) => write!(f, "exited with status {}", code),
+            LexError::Denied(capability) => write!(f, "capability {} is disabled", capability),
+            LexError::Import { path, reason } => {
+                write!(f, "failed to import \"{}\": {}", path, reason)
+            }
+            LexError::Io { path, message } => write!(f, "{}: {}", path, message),
+            LexError::Spanned(_, err) => err.fmt(f),
+            LexError::Many(errors) => {
+                let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+                write!(f, "{}", messages.join("\n"))
+            }
+        }
+    }
+}
+
+impl std::error::Error for LexError {}
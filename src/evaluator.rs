@@ -1,32 +1,61 @@
+use crate::error::LexError;
 use crate::grammar::token::Token;
 use crate::grammar::{document, repl_line};
+use crate::solver::Solver;
 use crate::state::State;
+use crate::utils::unify::Substitution;
 
 pub struct Engine {
     state: State,
 }
 
+impl Default for Engine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Engine {
     pub fn new() -> Self {
-        let mut res = Self {
-            state: State::new(),
-        };
+        let mut root = State::new();
+        root.load(crate::stdlib::Std);
+
+        // top level definitions are added to the parent of the evaluating
+        // scope, evaluate in a child of the stdlib so they are kept
+        Self {
+            state: root.child(),
+        }
+    }
+
+    pub fn state(&self) -> &State {
+        &self.state
+    }
 
-        res.state.load(crate::stdlib::Std);
+    // answer queries from relations computed bottom up, see `Datalog`
+    pub fn set_datalog(&mut self, enabled: bool) {
+        self.state.set_datalog(enabled);
+    }
 
-        res
+    // every capability is allowed by default, a denied one makes including
+    // the modules needing it fail
+    pub fn set_capability(&mut self, capability: &str, enabled: bool) {
+        self.state.set_capability(capability, enabled);
     }
 
-    pub fn parse(&mut self, input: &str) -> Result<Token, ()> {
-        let parse = document(input);
+    // arguments `.args` gives to the program
+    pub fn set_args(&mut self, args: Vec<String>) {
+        self.state.set_args(args);
+    }
 
-        let document: Token;
-        match parse {
-            Err(_) => return Err(()),
-            Ok((_, token)) => document = token,
-        };
+    // forget the memoized answers of `@table` relations
+    pub fn clear_tables(&mut self) {
+        self.state.tables().clear();
+    }
 
-        let result = self.state.exec(document);
+    pub fn parse(&mut self, input: &str) -> Result<Token, LexError> {
+        let document = document(input).map_err(|errors| LexError::parse_all(input, errors))?;
+
+        let result = self.state.exec(document)?;
         if let Some(value) = self.state.return_value() {
             return Ok(value);
         }
@@ -34,17 +63,16 @@ impl Engine {
         Ok(result)
     }
 
-    pub fn parse_line(&mut self, input: &str) -> Result<Token, ()> {
-        let parse = repl_line(input);
+    pub fn parse_line(&mut self, input: &str) -> Result<Token, LexError> {
+        let line = repl_line(input).map_err(|errors| LexError::parse_all(input, errors))?;
 
-        let document: Token;
-        match parse {
-            Err(_) => return Err(()),
-            Ok((_, token)) => document = token,
-        };
+        self.state.exec(line)
+    }
 
-        let result = self.state.exec(document);
+    // every solution of the goal on `input` against the definitions so far
+    pub fn query(&mut self, input: &str) -> Result<Vec<Substitution>, LexError> {
+        let goal = repl_line(input).map_err(|errors| LexError::parse_all(input, errors))?;
 
-        Ok(result)
+        Solver::new(self.state.clone()).all(&goal)
     }
 }
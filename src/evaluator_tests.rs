@@ -0,0 +1,213 @@
+#[cfg(test)]
+use super::{error::LexError, evaluator::Engine, grammar::token::Token};
+
+#[test]
+fn should_evaluate_document() {
+    let input = "@document test (+ 1 2)";
+
+    let res = Engine::new().parse(input);
+
+    assert_eq!(Ok(Token::List(vec![Token::Integer(3)])), res)
+}
+
+#[test]
+fn error_on_unclosed_list() {
+    let input = "@document test (+ 1 2) (+ 1";
+
+    let res = Engine::new().parse(input);
+
+    assert_eq!(
+        Err(LexError::Parse {
+            offset: 23,
+            message: "unclosed (, expected )".to_owned()
+        }),
+        res
+    )
+}
+
+#[test]
+fn error_on_unresolved_call() {
+    let input = "(.greet world)";
+
+    let res = Engine::new().parse_line(input).map_err(LexError::unspanned);
+
+    assert_eq!(
+        Err(LexError::Unresolved(Token::List(vec![
+            Token::Identifier("greet".to_owned()),
+            Token::Atom("world".to_owned()),
+        ]))),
+        res
+    )
+}
+
+#[test]
+fn error_on_type_mismatch() {
+    let input = "(+ 1 \"one\")";
+
+    let res = Engine::new().parse_line(input).map_err(LexError::unspanned);
+
+    assert_eq!(
+        Err(LexError::TypeMismatch {
+            expected: "number".to_owned(),
+            found: Token::List(vec![Token::Integer(1), Token::String("one".to_owned())]),
+        }),
+        res
+    )
+}
+
+#[test]
+fn error_on_arity_mismatch() {
+    let input = "(+ 1 2 3)";
+
+    let res = Engine::new().parse_line(input).map_err(LexError::unspanned);
+
+    assert_eq!(
+        Err(LexError::Arity {
+            expected: 2,
+            found: 3,
+            call: Token::List(vec![
+                Token::Operator("+".to_owned()),
+                Token::Integer(1),
+                Token::Integer(2),
+                Token::Integer(3),
+            ]),
+        }),
+        res
+    )
+}
+
+#[test]
+fn error_on_missing_import() {
+    let input = "(@include \"./does/not/exist.lx\")";
+
+    let res = Engine::new().parse_line(input).map_err(LexError::unspanned);
+
+    assert!(matches!(res, Err(LexError::Import { .. })))
+}
+
+#[test]
+fn should_point_at_failing_call() {
+    let input = "@document test\n(+ 1\n   (+ 2 \"two\"))";
+
+    let err = Engine::new().parse(input).unwrap_err();
+
+    assert_eq!(
+        r#"error: type mismatch: expected number, found (2 "two")
+ --> test.lx:3:4
+  |
+3 |    (+ 2 "two"))
+  |    ^^^^^^^^^^^"#,
+        err.render("test.lx", input)
+    )
+}
+
+#[test]
+fn should_point_at_definition_body() {
+    let input = "@document test\n(@def (.broken) (.missing))\n(.broken)";
+
+    let err = Engine::new().parse(input).unwrap_err();
+
+    assert_eq!(
+        r#"error: no definition matches (.missing)
+ --> test.lx:2:17
+  |
+2 | (@def (.broken) (.missing))
+  |                 ^^^^^^^^^^"#,
+        err.render("test.lx", input)
+    )
+}
+
+#[test]
+fn should_point_at_multibyte_characters() {
+    let input = "@document test\n(é";
+
+    let err = Engine::new().parse(input).unwrap_err();
+
+    assert_eq!(
+        r#"error: parse error: unclosed (, expected )
+ --> test.lx:2:1
+  |
+2 | (é
+  | ^
+
+error: parse error: unexpected character 'é'
+ --> test.lx:2:2
+  |
+2 | (é
+  |  ^"#,
+        err.render("test.lx", input)
+    )
+}
+
+#[test]
+fn should_not_point_into_other_sources() {
+    let mut engine = Engine::new();
+    engine.parse_line("(@def (.broken) (.missing))").unwrap();
+
+    let err = engine.parse_line("(.broken)").unwrap_err();
+
+    assert_eq!(
+        "error: no definition matches (.missing)",
+        err.render("<repl>", "(.broken)")
+    )
+}
+
+#[test]
+fn should_not_match_inconsistent_bindings() {
+    let input = "@document test (@def (X .same X) true) (a .same a) (a .same b)";
+
+    let res = Engine::new().parse(input);
+
+    assert_eq!(
+        Ok(Token::List(vec![
+            Token::_true(),
+            Token::_true(),
+            Token::List(vec![
+                Token::Atom("a".to_owned()),
+                Token::Identifier("same".to_owned()),
+                Token::Atom("b".to_owned()),
+            ]),
+        ])),
+        res.map(Token::unspanned)
+    )
+}
+
+#[test]
+fn should_pass_bindings_to_definition_body() {
+    let input = "@document test (@def (.double X) (+ X X)) (.double 4)";
+
+    let res = Engine::new().parse(input);
+
+    assert_eq!(
+        Ok(Token::List(vec![Token::_true(), Token::Integer(8)])),
+        res.map(Token::unspanned)
+    )
+}
+
+#[test]
+fn should_pass_keyword_arguments_by_name() {
+    let input = "@document test (@def (.greet to: Name from: Sender) (Sender Name)) (.greet from: bob to: alice)";
+
+    let res = Engine::new().parse(input);
+
+    assert_eq!(
+        Ok(Token::List(vec![
+            Token::_true(),
+            Token::List(vec![
+                Token::Atom("bob".to_owned()),
+                Token::Atom("alice".to_owned())
+            ]),
+        ])),
+        res.map(Token::unspanned)
+    )
+}
+
+#[test]
+fn error_on_keyword_argument_for_positional_parameter() {
+    let mut engine = Engine::new();
+    engine.parse_line("(@def (.f X) X)").unwrap();
+
+    let res = engine.parse_line("(.f to: a)").map_err(LexError::unspanned);
+
+    assert!(matches!(res, Err(LexError::Arity { .. })))
+}
@@ -1,11 +1,19 @@
+use super::error::IResult;
+use super::lexer::{Lexer, Scan, WORD};
 use super::token::Token;
-use nom::{bytes::complete::is_a, character::complete::one_of, IResult};
 
-pub fn atom(input: &str) -> IResult<&str, Token, ()> {
-    let (_, _) = one_of("abcdefghijklmnopqrstuvwxyz")(input)?;
+impl<'a> Lexer<'a> {
+    pub fn atom(&mut self) -> Scan {
+        if !self.peek()?.is_ascii_lowercase() {
+            return None;
+        }
 
-    let (input, value) =
-        is_a("_abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ1234567890")(input)?;
+        let value = self.eat_while(|c| WORD.contains(c));
 
-    Ok((&input, Token::Atom(value.to_owned())))
+        Some(Ok(Token::Atom(value.to_owned())))
+    }
+}
+
+pub fn atom(input: &str) -> IResult<&str, Token> {
+    Lexer::scan(input, "atom", Lexer::atom)
 }
@@ -1,7 +1,7 @@
 #[cfg(test)]
-use super::{atom::atom, token::Token};
+use super::error::Err;
 #[cfg(test)]
-use nom::Err;
+use super::{atom::atom, error::ParseError, token::Token};
 
 #[test]
 fn should_be_valid_with_underscore() {
@@ -18,7 +18,7 @@ fn error_start_with_capital() {
 
     let res = atom(input);
 
-    assert_eq!(Err(Err::Error(())), res)
+    assert_eq!(Err(Err::Error(ParseError::new(input, "atom"))), res)
 }
 
 #[test]
@@ -27,7 +27,7 @@ fn error_start_with_number() {
 
     let res = atom(input);
 
-    assert_eq!(Err(Err::Error(())), res)
+    assert_eq!(Err(Err::Error(ParseError::new(input, "atom"))), res)
 }
 
 #[test]
@@ -36,5 +36,5 @@ fn error_start_with_symbol() {
 
     let res = atom(input);
 
-    assert_eq!(Err(Err::Error(())), res)
+    assert_eq!(Err(Err::Error(ParseError::new(input, "atom"))), res)
 }
@@ -1,8 +1,23 @@
+use super::error::IResult;
+use super::lexer::{Lexer, Scan, WORD};
 use super::token::Token;
-use nom::{branch::alt, bytes::complete::tag, IResult};
 
-pub fn boolean(input: &str) -> IResult<&str, Token, ()> {
-    let (input, value) = alt((tag("true"), tag("false")))(input)?;
+impl<'a> Lexer<'a> {
+    pub fn boolean(&mut self) -> Scan {
+        let start = self.position();
+        let value = self.eat_while(|c| WORD.contains(c));
 
-    Ok((&input, Token::Boolean(value == "true")))
+        match value {
+            "true" => Some(Ok(Token::Boolean(true))),
+            "false" => Some(Ok(Token::Boolean(false))),
+            _ => {
+                self.reset(start);
+                None
+            }
+        }
+    }
+}
+
+pub fn boolean(input: &str) -> IResult<&str, Token> {
+    Lexer::scan(input, "boolean", Lexer::boolean)
 }
@@ -1,12 +1,25 @@
+use super::error::IResult;
+use super::lexer::{Lexer, Scan};
 use super::token::Token;
-use nom::{
-    bytes::complete::{tag, take_until},
-    sequence::tuple,
-    IResult,
-};
 
-pub fn comment(input: &str) -> IResult<&str, Token, ()> {
-    let (input, (_, _value, _)) = tuple((tag("/*"), take_until("*/"), tag("*/")))(input)?;
+impl<'a> Lexer<'a> {
+    pub fn comment(&mut self) -> Scan {
+        if !self.starts_with("/*") {
+            return None;
+        }
 
-    Ok((&input, Token::Comment))
+        self.bump_str("/*");
+        while !self.starts_with("*/") {
+            if self.bump().is_none() {
+                return Some(Err("unterminated comment".to_owned()));
+            }
+        }
+        self.bump_str("*/");
+
+        Some(Ok(Token::Comment))
+    }
+}
+
+pub fn comment(input: &str) -> IResult<&str, Token> {
+    Lexer::scan(input, "comment", Lexer::comment)
 }
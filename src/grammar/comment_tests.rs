@@ -1,7 +1,7 @@
 #[cfg(test)]
-use super::{comment::comment, token::Token};
+use super::error::{Err, IResult};
 #[cfg(test)]
-use nom::{Err, IResult};
+use super::{comment::comment, error::ParseError, token::Token};
 
 #[test]
 fn should_be_able_to_comment() {
@@ -29,5 +29,8 @@ fn error_with_missing_pair() {
 
     let res = comment(input);
 
-    assert_eq!(IResult::Err(Err::Error(())), res)
+    assert_eq!(
+        IResult::Err(Err::Error(ParseError::new(input, "comment"))),
+        res
+    )
 }
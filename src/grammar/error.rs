@@ -0,0 +1,34 @@
+pub type IResult<I, O, E = ParseError> = Result<(I, O), Err<E>>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Err<E> {
+    Error(E),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    // length of the input left when the parser failed,
+    // the offset in the source is `source.len() - remaining`
+    pub remaining: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(input: &str, expected: &str) -> Self {
+        Self {
+            remaining: input.len(),
+            message: format!("expected {}", expected),
+        }
+    }
+
+    pub fn at(source: &str, offset: usize, message: &str) -> Self {
+        Self {
+            remaining: source.len().saturating_sub(offset),
+            message: message.to_owned(),
+        }
+    }
+
+    pub fn offset(&self, source: &str) -> usize {
+        source.len().saturating_sub(self.remaining)
+    }
+}
@@ -1,15 +1,23 @@
+use super::error::IResult;
+use super::lexer::{Lexer, Scan, WORD};
 use super::token::Token;
-use nom::{
-    bytes::complete::{is_a, tag},
-    sequence::pair,
-    IResult,
-};
-
-pub fn identifier(input: &str) -> IResult<&str, Token, ()> {
-    let (input, (_, value)) = pair(
-        tag("."),
-        is_a("_abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ1234567890"),
-    )(input)?;
-
-    Ok((&input, Token::Identifier(value.to_owned())))
+
+impl<'a> Lexer<'a> {
+    pub fn identifier(&mut self) -> Scan {
+        if self.peek()? != '.' {
+            return None;
+        }
+
+        self.bump();
+        let value = self.eat_while(|c| WORD.contains(c));
+        if value.is_empty() {
+            return Some(Err("expected identifier".to_owned()));
+        }
+
+        Some(Ok(Token::Identifier(value.to_owned())))
+    }
+}
+
+pub fn identifier(input: &str) -> IResult<&str, Token> {
+    Lexer::scan(input, "identifier", Lexer::identifier)
 }
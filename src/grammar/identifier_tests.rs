@@ -1,7 +1,7 @@
 #[cfg(test)]
-use super::{identifier::identifier, token::Token};
+use super::error::Err;
 #[cfg(test)]
-use nom::Err;
+use super::{error::ParseError, identifier::identifier, token::Token};
 
 #[test]
 fn should_be_valid_identifier() {
@@ -18,5 +18,5 @@ fn error_missing_dot() {
 
     let res = identifier(input);
 
-    assert_eq!(Err(Err::Error(())), res)
+    assert_eq!(Err(Err::Error(ParseError::new(input, "identifier"))), res)
 }
@@ -1,12 +1,19 @@
+use super::error::IResult;
+use super::lexer::{Lexer, Scan};
 use super::token::Token;
-use nom::{
-    bytes::complete::{is_not, tag},
-    sequence::pair,
-    IResult,
-};
 
-pub fn inline_comment(input: &str) -> IResult<&str, Token, ()> {
-    let (input, (_, _value)) = pair(tag("//"), is_not("\n\r"))(input)?;
+impl<'a> Lexer<'a> {
+    pub fn inline_comment(&mut self) -> Scan {
+        if !self.starts_with("//") {
+            return None;
+        }
 
-    Ok((&input, Token::Comment))
+        self.eat_while(|c| c != '\n' && c != '\r');
+
+        Some(Ok(Token::Comment))
+    }
+}
+
+pub fn inline_comment(input: &str) -> IResult<&str, Token> {
+    Lexer::scan(input, "comment", Lexer::inline_comment)
 }
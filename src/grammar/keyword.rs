@@ -1,25 +1,28 @@
+use super::error::IResult;
+use super::lexer::{Lexer, Scan, WORD};
 use super::token::Token;
-use nom::{branch::alt, bytes::complete::tag, sequence::pair, IResult};
 
-pub fn keyword<'a>(word: &'a str) -> impl Fn(&'a str) -> IResult<&'a str, Token, ()> {
-    move |input: &str| {
-        let (input, (_, value)) = pair(tag("@"), tag(word))(input)?;
+pub const KEYWORDS: &[&str] = &[
+    "include", "return", "def", "dec", "do", "and", "or", "div", "table", "if", "cond", "match",
+    "let", "fn",
+];
 
-        Ok((&input, Token::Keyword(value.to_owned())))
+impl<'a> Lexer<'a> {
+    pub fn keyword(&mut self) -> Scan {
+        if self.peek()? != '@' {
+            return None;
+        }
+
+        self.bump();
+        let value = self.eat_while(|c| WORD.contains(c));
+        if !KEYWORDS.contains(&value) {
+            return Some(Err(format!("unknown keyword @{}", value)));
+        }
+
+        Some(Ok(Token::Keyword(value.to_owned())))
     }
 }
 
-pub fn valid_keyword(input: &str) -> IResult<&str, Token, ()> {
-    let res = alt((
-        keyword("include"),
-        keyword("return"),
-        keyword("def"),
-        keyword("dec"),
-        keyword("do"),
-        keyword("and"),
-        keyword("or"),
-        keyword("div"),
-    ))(input)?;
-
-    Ok(res)
+pub fn valid_keyword(input: &str) -> IResult<&str, Token> {
+    Lexer::scan(input, "keyword", Lexer::keyword)
 }
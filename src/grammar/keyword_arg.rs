@@ -0,0 +1,39 @@
+use super::error::{Err, IResult, ParseError};
+use super::lexer::{Lexer, Scan, WORD};
+use super::parser::Parser;
+use super::token::Token;
+
+impl<'a> Lexer<'a> {
+    // the `name:` part of a keyword argument, the parser reads its value
+    pub fn keyword_arg(&mut self) -> Scan {
+        if !self.peek()?.is_ascii_lowercase() {
+            return None;
+        }
+
+        let start = self.position();
+        let value = self.eat_while(|c| WORD.contains(c));
+        if self.peek() != Some(':') {
+            self.reset(start);
+            return None;
+        }
+
+        self.bump();
+        Some(Ok(Token::KeywordArg(
+            value.to_owned(),
+            Box::new(Token::Value),
+        )))
+    }
+}
+
+pub fn keyword_arg(input: &str) -> IResult<&str, Token> {
+    let mut parser = Parser::new(input);
+    let res = parser.item();
+
+    match res {
+        Some(token) if parser.errors().is_empty() => match token.inner() {
+            Token::KeywordArg(_, _) => Ok((parser.rest(), token.unspanned())),
+            _ => Err(Err::Error(ParseError::new(input, "keyword argument"))),
+        },
+        _ => Err(Err::Error(ParseError::new(input, "keyword argument"))),
+    }
+}
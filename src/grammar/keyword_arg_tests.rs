@@ -0,0 +1,70 @@
+#[cfg(test)]
+use super::error::Err;
+#[cfg(test)]
+use super::{error::ParseError, keyword_arg::keyword_arg, list::list, token::Token};
+
+#[test]
+fn should_be_valid_keyword_arg() {
+    let input = "where: (= ID 10)";
+
+    let res = keyword_arg(input);
+
+    assert_eq!(
+        Ok((
+            "",
+            Token::KeywordArg(
+                "where".to_owned(),
+                Box::new(Token::List(vec![
+                    Token::Operator("=".to_owned()),
+                    Token::Variable("ID".to_owned(), None),
+                    Token::Integer(10),
+                ]))
+            )
+        )),
+        res
+    )
+}
+
+#[test]
+fn error_on_missing_value() {
+    let input = "where:";
+
+    let res = keyword_arg(input);
+
+    assert_eq!(
+        Err(Err::Error(ParseError::new(input, "keyword argument"))),
+        res
+    )
+}
+
+#[test]
+fn error_on_atom() {
+    let input = "where (= ID 10)";
+
+    let res = keyword_arg(input);
+
+    assert_eq!(
+        Err(Err::Error(ParseError::new(input, "keyword argument"))),
+        res
+    )
+}
+
+#[test]
+fn should_move_keyword_args_to_the_end_by_name() {
+    let input = "(.select where: true Cols from: a)";
+
+    let res = list(input).map(|(rest, token)| (rest, token.unspanned()));
+
+    assert_eq!(
+        Ok((
+            "",
+            Token::List(vec![
+                Token::Identifier("select".to_owned()),
+                Token::Variable("Cols".to_owned(), None),
+                Token::KeywordArg("from".to_owned(), Box::new(Token::Atom("a".to_owned()))),
+                Token::KeywordArg("where".to_owned(), Box::new(Token::Boolean(true))),
+            ])
+        )),
+        res
+    )
+}
@@ -0,0 +1,41 @@
+#[cfg(test)]
+use super::error::Err;
+#[cfg(test)]
+use super::{error::ParseError, keyword::valid_keyword, repl_line, token::Token};
+
+#[test]
+fn should_be_valid_keyword() {
+    for name in ["and", "or", "do", "div", "if", "cond", "match", "let", "fn"] {
+        let input = format!("@{}", name);
+
+        let res = valid_keyword(&input);
+
+        assert_eq!(Ok(("", Token::Keyword(name.to_owned()))), res)
+    }
+}
+
+#[test]
+fn should_parse_keyword_with_any_number_of_operands() {
+    let input = "(@and a b c)";
+
+    let res = repl_line(input).unwrap().unspanned();
+
+    assert_eq!(
+        Token::List(vec![
+            Token::Keyword("and".to_owned()),
+            Token::Atom("a".to_owned()),
+            Token::Atom("b".to_owned()),
+            Token::Atom("c".to_owned()),
+        ]),
+        res
+    )
+}
+
+#[test]
+fn error_on_unknown_keyword() {
+    let input = "@xor";
+
+    let res = valid_keyword(input);
+
+    assert_eq!(Err(Err::Error(ParseError::new(input, "keyword"))), res)
+}
@@ -0,0 +1,217 @@
+use super::error::{Err, IResult, ParseError};
+use super::span::Span;
+use super::token::Token;
+
+pub const WORD: &str = "_abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ1234567890";
+
+// result of a single scanner, `None` when the input does not start with
+// the kind of token the scanner is looking for (nothing is consumed),
+// `Some(Err(..))` when it does but the token is malformed
+pub type Scan = Option<Result<Token, String>>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Lexeme {
+    Open,
+    Close,
+    Begin,
+    End,
+    Document,
+    Token(Token),
+    Error(String),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Position {
+    offset: usize,
+    line: usize,
+    line_start: usize,
+}
+
+pub struct Lexer<'a> {
+    source: &'a str,
+    id: u64,
+    pos: Position,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            id: Span::id(source),
+            pos: Position {
+                offset: 0,
+                line: 1,
+                line_start: 0,
+            },
+        }
+    }
+
+    pub fn source(&self) -> &'a str {
+        self.source
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.pos.offset..]
+    }
+
+    pub fn position(&self) -> Position {
+        self.pos
+    }
+
+    pub fn reset(&mut self, pos: Position) {
+        self.pos = pos;
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub fn peek_nth(&self, n: usize) -> Option<char> {
+        self.rest().chars().nth(n)
+    }
+
+    pub fn starts_with(&self, pattern: &str) -> bool {
+        self.rest().starts_with(pattern)
+    }
+
+    pub fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos.offset += c.len_utf8();
+        if c == '\n' {
+            self.pos.line += 1;
+            self.pos.line_start = self.pos.offset;
+        }
+
+        Some(c)
+    }
+
+    pub fn bump_str(&mut self, pattern: &str) {
+        for _ in pattern.chars() {
+            self.bump();
+        }
+    }
+
+    pub fn eat_while<F: Fn(char) -> bool>(&mut self, f: F) -> &'a str {
+        let start = self.pos.offset;
+        while let Some(c) = self.peek() {
+            if !f(c) {
+                break;
+            }
+            self.bump();
+        }
+
+        &self.source[start..self.pos.offset]
+    }
+
+    pub fn span(&self, start: Position) -> Span {
+        Span {
+            source: self.id,
+            start: start.offset,
+            end: self.pos.offset,
+            line: start.line,
+            column: self.source[start.line_start..start.offset].chars().count() + 1,
+        }
+    }
+
+    // run a single scanner on the whole input, used to expose every
+    // token kind as a standalone parser
+    pub fn scan<F>(input: &'a str, expected: &str, scanner: F) -> IResult<&'a str, Token>
+    where
+        F: Fn(&mut Self) -> Scan,
+    {
+        let mut lexer = Self::new(input);
+        match scanner(&mut lexer) {
+            Some(Ok(token)) => Ok((lexer.rest(), token)),
+            _ => Err(Err::Error(ParseError::new(input, expected))),
+        }
+    }
+
+    // skip whitespace and comments, reporting unterminated comments
+    fn skip(&mut self) -> Option<(Lexeme, Span)> {
+        loop {
+            let start = self.position();
+            let scan = self
+                .whitespace()
+                .or_else(|| self.comment())
+                .or_else(|| self.inline_comment());
+
+            match scan {
+                Some(Ok(_)) => continue,
+                Some(Err(message)) => return Some((Lexeme::Error(message), self.span(start))),
+                None => return None,
+            }
+        }
+    }
+
+    pub fn next_lexeme(&mut self) -> Option<(Lexeme, Span)> {
+        if let Some(error) = self.skip() {
+            return Some(error);
+        }
+
+        let start = self.position();
+        let c = self.peek()?;
+
+        let lexeme = match c {
+            '(' => {
+                self.bump();
+                Lexeme::Open
+            }
+            ')' => {
+                self.bump();
+                Lexeme::Close
+            }
+            '@' => match self.structural_keyword() {
+                Some(lexeme) => lexeme,
+                None => match self.keyword() {
+                    Some(Ok(token)) => Lexeme::Token(token),
+                    Some(Err(message)) => Lexeme::Error(message),
+                    None => {
+                        self.bump();
+                        Lexeme::Error("expected keyword".to_owned())
+                    }
+                },
+            },
+            _ => {
+                let scan = self
+                    .string()
+                    .or_else(|| self.boolean())
+                    .or_else(|| self.keyword_arg())
+                    .or_else(|| self.atom())
+                    .or_else(|| self.variable())
+                    .or_else(|| self.wildcard())
+                    .or_else(|| self.identifier())
+                    .or_else(|| self.number())
+                    .or_else(|| self.operator());
+
+                match scan {
+                    Some(Ok(token)) => Lexeme::Token(token),
+                    Some(Err(message)) => Lexeme::Error(message),
+                    None => {
+                        self.bump();
+                        Lexeme::Error(format!("unexpected character {:?}", c))
+                    }
+                }
+            }
+        };
+
+        Some((lexeme, self.span(start)))
+    }
+
+    fn structural_keyword(&mut self) -> Option<Lexeme> {
+        let start = self.position();
+        self.bump();
+        let word = self.eat_while(|c| WORD.contains(c));
+
+        let lexeme = match word {
+            "begin" => Lexeme::Begin,
+            "end" => Lexeme::End,
+            "document" => Lexeme::Document,
+            _ => {
+                self.reset(start);
+                return None;
+            }
+        };
+
+        Some(lexeme)
+    }
+}
@@ -1,81 +1,45 @@
-use super::keyword::keyword;
-use super::token::{token, Token};
-use super::whitespace::whitespace;
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    multi::{many0, many1, separated_list1},
-    sequence::tuple,
-    Err, IResult,
-};
-
-pub fn l_parent(input: &str) -> IResult<&str, Token, ()> {
-    let (input, _) = tag("(")(input)?;
-
-    Ok((input, Token::LParent))
-}
-
-pub fn r_parent(input: &str) -> IResult<&str, Token, ()> {
-    let (input, _) = tag(")")(input)?;
-
-    Ok((input, Token::RParent))
-}
-
-pub fn list(input: &str) -> IResult<&str, Token, ()> {
-    if input.len() < 2 {
-        return Err(Err::Error(()));
+use super::error::{Err, IResult, ParseError};
+use super::parser::Parser;
+use super::token::Token;
+
+pub fn list(input: &str) -> IResult<&str, Token> {
+    let mut parser = Parser::new(input);
+    let res = parser.item();
+
+    match res {
+        Some(token) if parser.errors().is_empty() => match token.inner() {
+            Token::List(_) | Token::Definition(_, _) => Ok((parser.rest(), token.inner().clone())),
+            _ => Err(Err::Error(ParseError::new(input, "list"))),
+        },
+        _ => Err(Err::Error(ParseError::new(input, "list"))),
     }
+}
 
-    if input.len() == 2 {
-        let (input, _) = tuple((tag("("), tag(")")))(input)?;
-        return Ok((&input, Token::List(vec![])));
+// check for list variant
+pub fn list_variant(result: Vec<Token>) -> Token {
+    match (result.first().map(Token::inner), &result[..]) {
+        (Some(Token::Keyword(key)), [_, a, b]) if key == "def" => {
+            Token::Definition(Box::new(a.clone()), Box::new(b.clone()))
+        }
+        (Some(Token::Keyword(key)), [_, a]) if key == "dec" => {
+            Token::Definition(Box::new(a.clone()), Box::new(Token::_true()))
+        }
+        _ => Token::List(keyword_args_last(result)),
     }
+}
 
-    let element = separated_list1(many1(whitespace), token);
-    let with_parent = tuple((
-        l_parent,
-        many0(whitespace),
-        element,
-        many0(whitespace),
-        r_parent,
-    ));
-
-    let element1 = separated_list1(many1(whitespace), token);
-    let with_keyword = tuple((
-        keyword("begin"),
-        many0(whitespace),
-        element1,
-        many0(whitespace),
-        keyword("end"),
-    ));
-
-    let (input, (_, _, value, _, _)) = alt((with_parent, with_keyword))(input)?;
-
-    // ignore comment
-    let result: Vec<Token> = value
-        .clone()
+// keyword arguments go after the positional ones, ordered by name, so calls
+// match a signature whatever order the arguments were written in
+fn keyword_args_last(items: Vec<Token>) -> Vec<Token> {
+    let (mut res, mut named): (Vec<Token>, Vec<Token>) = items
         .into_iter()
-        .filter(|e| *e != Token::Comment)
-        .collect();
+        .partition(|item| !matches!(item.inner(), Token::KeywordArg(_, _)));
 
-    // check for list variant
-    let res = match &result[..] {
-        [Token::Keyword(key), a, b] => {
-            if key == "def" {
-                Token::Definition(Box::new(a.clone()), Box::new(b.clone()))
-            } else {
-                Token::List(result)
-            }
-        }
-        [Token::Keyword(key), a] => {
-            if key == "dec" {
-                Token::Definition(Box::new(a.clone()), Box::new(Token::_true()))
-            } else {
-                Token::List(result)
-            }
-        }
-        _ => Token::List(result),
-    };
+    named.sort_by(|a, b| match (a.inner(), b.inner()) {
+        (Token::KeywordArg(a, _), Token::KeywordArg(b, _)) => a.cmp(b),
+        _ => std::cmp::Ordering::Equal,
+    });
+    res.extend(named);
 
-    Ok((&input, res))
+    res
 }
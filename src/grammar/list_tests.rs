@@ -1,7 +1,7 @@
 #[cfg(test)]
-use super::{list::list, token::Token};
+use super::error::Err;
 #[cfg(test)]
-use nom::Err;
+use super::{error::ParseError, list::list, token::Token};
 
 #[test]
 fn should_be_valid_list() {
@@ -13,9 +13,9 @@ fn should_be_valid_list() {
         Ok((
             "",
             Token::List(vec![
-                Token::Number(1 as f64),
-                Token::Number(1 as f64),
-                Token::Number(1 as f64),
+                Token::Integer(1),
+                Token::Integer(1),
+                Token::Integer(1),
             ])
         )),
         res
@@ -36,9 +36,9 @@ fn should_be_valid_multiline_list() {
         Ok((
             "",
             Token::List(vec![
-                Token::Number(1 as f64),
-                Token::Number(1 as f64),
-                Token::Number(1 as f64),
+                Token::Integer(1),
+                Token::Integer(1),
+                Token::Integer(1),
             ])
         )),
         res
@@ -56,8 +56,8 @@ fn should_be_valid_list_of_mixed_type() {
             "",
             Token::List(vec![
                 Token::String("hello world".to_owned()),
-                Token::Number(1 as f64),
-                Token::Number(1 as f64),
+                Token::Integer(1),
+                Token::Integer(1),
                 Token::Variable("Hello".to_owned(), None),
                 Token::Atom("atom".to_owned()),
             ])
@@ -86,8 +86,8 @@ fn should_be_valid_nested_list() {
             "",
             Token::List(vec![
                 Token::String("hello world".to_owned()),
-                Token::List(vec![Token::Number(1 as f64)]),
-                Token::Number(1 as f64),
+                Token::List(vec![Token::Integer(1)]),
+                Token::Integer(1),
             ])
         )),
         res
@@ -100,5 +100,5 @@ fn error_missing_parentheses() {
 
     let res = list(input);
 
-    assert_eq!(Err(Err::Error(())), res)
+    assert_eq!(Err(Err::Error(ParseError::new(input, "list"))), res)
 }
@@ -1,7 +1,8 @@
-/*
- * TODO: write custom parser,
- * using nom for quicker prototyping
- */
+pub mod error;
+pub mod lexer;
+pub mod parser;
+pub mod span;
+pub mod span_tests;
 pub mod token;
 
 pub mod atom;
@@ -15,12 +16,16 @@ pub mod identifier_tests;
 pub mod inline_comment;
 pub mod inline_comment_tests;
 pub mod keyword;
+pub mod keyword_arg;
+pub mod keyword_arg_tests;
+pub mod keyword_tests;
 pub mod list;
 pub mod list_tests;
 pub mod number;
 pub mod number_tests;
 pub mod operator;
 pub mod operator_tests;
+pub mod parser_tests;
 pub mod string;
 pub mod string_tests;
 pub mod variable;
@@ -29,70 +34,28 @@ pub mod whitespace;
 pub mod wildcard;
 pub mod wildcard_tests;
 
-use atom::atom;
-use boolean::boolean;
-use identifier::identifier;
-use keyword::keyword;
-use list::list;
-use nom::{
-    branch::alt,
-    multi::{many0, many1},
-    sequence::tuple,
-    IResult,
-};
-use number::number;
-use operator::operator;
-use string::string;
+use error::ParseError;
+use parser::Parser;
 use token::Token;
-use variable::variable;
-use whitespace::whitespace;
-use wildcard::wildcard;
 
-pub fn repl_line(input: &str) -> IResult<&str, Token, ()> {
-    let value = alt((
-        boolean, string, atom, identifier, list, number, operator, variable, wildcard,
-    ));
-    let (inp, (_, value, _)) = tuple((many0(whitespace), value, many0(whitespace)))(input)?;
+pub fn repl_line(input: &str) -> Result<Token, Vec<ParseError>> {
+    let mut parser = Parser::new(input);
+    let line = parser.line();
 
-    Ok((inp, value))
+    match parser.into_errors() {
+        errors if errors.is_empty() => Ok(line),
+        errors => Err(errors),
+    }
 }
 
-pub fn document(input: &str) -> IResult<&str, Token, ()> {
-    let ignore1 = alt((
-        comment::comment,
-        whitespace::whitespace,
-        inline_comment::inline_comment,
-    ));
-    let ignore2 = alt((
-        comment::comment,
-        whitespace::whitespace,
-        inline_comment::inline_comment,
-    ));
+pub fn document(input: &str) -> Result<Token, Vec<ParseError>> {
+    let mut parser = Parser::new(input);
+    let document = parser.document();
 
-    let list = many1(alt((
-        comment::comment,
-        inline_comment::inline_comment,
-        token::token,
-        whitespace::whitespace,
-    )));
-
-    let (input, (_, _, _, name, value)) = tuple((
-        many0(ignore1),
-        keyword("document"),
-        many0(ignore2),
-        alt((string::string, atom::atom)),
-        list,
-    ))(input)?;
-
-    let value: Vec<Token> = value
-        .into_iter()
-        .filter(|token| *token != Token::Whitespace && *token != Token::Comment)
-        .collect();
-
-    Ok((
-        input,
-        Token::Document(Box::new(name), Box::new(Token::List(value))),
-    ))
+    match parser.into_errors() {
+        errors if errors.is_empty() => Ok(document),
+        errors => Err(errors),
+    }
 }
 
 #[test]
@@ -107,7 +70,7 @@ fn should_be_able_to_parse_document() {
 //some comment
 "#;
 
-    let (_, res) = document(doc).unwrap();
+    let res = document(doc).unwrap();
 
     assert_eq!(
         Token::Document(
@@ -1,8 +1,91 @@
+use super::error::IResult;
+use super::lexer::{Lexer, Scan};
 use super::token::Token;
-use nom::{number::complete::double, IResult};
 
-pub fn number(input: &str) -> IResult<&str, Token, ()> {
-    let (input, value) = double(input)?;
+impl<'a> Lexer<'a> {
+    // integers are written without a fraction or exponent, in decimal or
+    // with a `0x`, `0o` or `0b` prefix, digits can be separated with `_`
+    pub fn number(&mut self) -> Scan {
+        let sign = matches!(self.peek()?, '-' | '+') as usize;
+        if !self.peek_nth(sign)?.is_ascii_digit() {
+            return None;
+        }
 
-    Ok((input, Token::Number(value)))
+        let start = self.rest();
+        for _ in 0..sign {
+            self.bump();
+        }
+
+        let radix = match (self.peek(), self.peek_nth(1)) {
+            (Some('0'), Some('x' | 'X')) => 16,
+            (Some('0'), Some('o' | 'O')) => 8,
+            (Some('0'), Some('b' | 'B')) => 2,
+            _ => 10,
+        };
+        if radix != 10 {
+            self.bump_str("0x");
+            let digits = self.eat_while(|c| c.is_ascii_alphanumeric() || c == '_');
+            let digits: String = digits.chars().filter(|c| *c != '_').collect();
+
+            return Some(integer(&start[..sign], &digits, radix));
+        }
+
+        let digits = |c: char| c.is_ascii_digit() || c == '_';
+        self.eat_while(digits);
+        let mut float = false;
+
+        if self.peek() == Some('.') && self.peek_nth(1).is_some_and(|c| c.is_ascii_digit()) {
+            self.bump();
+            self.eat_while(digits);
+            float = true;
+        }
+
+        if let Some('e' | 'E') = self.peek() {
+            let sign = matches!(self.peek_nth(1), Some('-' | '+')) as usize;
+            if self.peek_nth(1 + sign).is_some_and(|c| c.is_ascii_digit()) {
+                for _ in 0..=sign {
+                    self.bump();
+                }
+                self.eat_while(digits);
+                float = true;
+            }
+        }
+
+        let len = start.len() - self.rest().len();
+        let text: String = start[..len].chars().filter(|c| *c != '_').collect();
+
+        if !float {
+            return Some(
+                text.parse::<i64>()
+                    .map(Token::Integer)
+                    .map_err(|_| "decimal literal out of range".to_owned()),
+            );
+        }
+
+        match text.parse::<f64>() {
+            Ok(value) => Some(Ok(Token::Number(value))),
+            Err(_) => Some(Err("invalid number".to_owned())),
+        }
+    }
+}
+
+fn integer(sign: &str, digits: &str, radix: u32) -> Result<Token, String> {
+    let name = match radix {
+        16 => "hexadecimal",
+        8 => "octal",
+        _ => "binary",
+    };
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(format!("invalid {} literal", name));
+    }
+
+    match i64::from_str_radix(&format!("{}{}", sign, digits), radix) {
+        Ok(value) => Ok(Token::Integer(value)),
+        Err(_) => Err(format!("{} literal out of range", name)),
+    }
+}
+
+pub fn number(input: &str) -> IResult<&str, Token> {
+    Lexer::scan(input, "number", Lexer::number)
 }
@@ -2,6 +2,7 @@
 use super::{number::number, token::Token};
 
 #[test]
+#[allow(clippy::approx_constant)]
 fn should_be_valid_decimal_number() {
     let input = "3.14";
 
@@ -9,3 +10,65 @@ fn should_be_valid_decimal_number() {
 
     assert_eq!(Ok(("", Token::Number(3.14))), res)
 }
+
+#[test]
+fn should_be_valid_integer() {
+    assert_eq!(Ok(("", Token::Integer(42))), number("42"));
+    assert_eq!(Ok(("", Token::Integer(-7))), number("-7"));
+    assert_eq!(Ok(("", Token::Integer(1_000_000))), number("1_000_000"));
+}
+
+#[test]
+fn should_be_valid_float_with_exponent() {
+    assert_eq!(Ok(("", Token::Number(1500.0))), number("1.5e3"));
+    assert_eq!(Ok(("", Token::Number(0.01))), number("1e-2"));
+}
+
+#[test]
+fn should_be_valid_prefixed_integer() {
+    assert_eq!(Ok(("", Token::Integer(255))), number("0xff"));
+    assert_eq!(Ok(("", Token::Integer(-255))), number("-0xFF"));
+    assert_eq!(Ok(("", Token::Integer(8))), number("0o10"));
+    assert_eq!(Ok(("", Token::Integer(10))), number("0b1010"));
+    assert_eq!(Ok(("", Token::Integer(0xdead_beef))), number("0xdead_beef"));
+}
+
+#[test]
+fn error_on_invalid_prefixed_integer() {
+    let input = "@document test\n(0b102 0x 0xffffffffffffffffff)";
+
+    let res = super::document(input)
+        .unwrap_err()
+        .into_iter()
+        .map(|err| (err.offset(input), err.message))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        vec![
+            (16, "invalid binary literal".to_owned()),
+            (22, "invalid hexadecimal literal".to_owned()),
+            (25, "hexadecimal literal out of range".to_owned()),
+        ],
+        res
+    )
+}
+
+#[test]
+fn error_on_decimal_integer_out_of_range() {
+    let input = "@document test\n(99999999999999999999 9223372036854775807)";
+
+    let res = super::document(input)
+        .unwrap_err()
+        .into_iter()
+        .map(|err| (err.offset(input), err.message))
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec![(16, "decimal literal out of range".to_owned())], res)
+}
+
+#[test]
+fn should_read_large_decimal_with_fraction_as_float() {
+    let res = number("99999999999999999999.0");
+
+    assert_eq!(Ok(("", Token::Number(1e20))), res)
+}
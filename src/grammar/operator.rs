@@ -1,22 +1,20 @@
+use super::error::IResult;
+use super::lexer::{Lexer, Scan};
 use super::token::Token;
-use nom::{branch::alt, bytes::complete::tag, IResult};
 
-pub fn operator(input: &str) -> IResult<&str, Token, ()> {
-    let (input, value) = alt((
-        tag("!="),
-        tag(">="),
-        tag("<="),
-        tag("="),
-        tag(">"),
-        tag("<"),
-        tag("!"),
-        tag("-"),
-        tag("+"),
-        tag("/"),
-        tag("*"),
-        tag("?"),
-        tag("!"),
-    ))(input)?;
+pub const OPERATORS: &[&str] = &[
+    "!=", ">=", "<=", "=", ">", "<", "!", "-", "+", "/", "*", "?",
+];
 
-    Ok((&input, Token::Operator(value.to_owned())))
+impl<'a> Lexer<'a> {
+    pub fn operator(&mut self) -> Scan {
+        let value = OPERATORS.iter().find(|op| self.starts_with(op))?;
+        self.bump_str(value);
+
+        Some(Ok(Token::Operator(value.to_string())))
+    }
+}
+
+pub fn operator(input: &str) -> IResult<&str, Token> {
+    Lexer::scan(input, "operator", Lexer::operator)
 }
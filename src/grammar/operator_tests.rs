@@ -1,7 +1,7 @@
 #[cfg(test)]
-use super::{operator::operator, token::Token};
+use super::error::Err;
 #[cfg(test)]
-use nom::Err;
+use super::{error::ParseError, operator::operator, token::Token};
 
 #[test]
 fn should_be_valid_operator() {
@@ -18,5 +18,5 @@ fn error_invalid_symbol() {
 
     let res = operator(input);
 
-    assert_eq!(Err(Err::Error(())), res)
+    assert_eq!(Err(Err::Error(ParseError::new(input, "operator"))), res)
 }
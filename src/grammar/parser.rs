@@ -0,0 +1,208 @@
+use super::error::ParseError;
+use super::lexer::{Lexeme, Lexer};
+use super::list::list_variant;
+use super::span::Span;
+use super::token::Token;
+
+// recursive descent parser over the lexer, instead of stopping at the first
+// problem it records the error, skips the offending input and carries on
+// so a whole document can be checked in one pass
+pub struct Parser<'a> {
+    lexer: Lexer<'a>,
+    peeked: Option<(Lexeme, Span)>,
+    errors: Vec<ParseError>,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            lexer: Lexer::new(source),
+            peeked: None,
+            errors: vec![],
+        }
+    }
+
+    pub fn errors(&self) -> &[ParseError] {
+        &self.errors
+    }
+
+    pub fn into_errors(self) -> Vec<ParseError> {
+        let mut errors = self.errors;
+        errors.sort_by_key(|err| std::cmp::Reverse(err.remaining));
+
+        errors
+    }
+
+    // input left after the last lexeme consumed by the parser
+    pub fn rest(&self) -> &'a str {
+        match &self.peeked {
+            Some((_, span)) => &self.lexer.source()[span.start..],
+            None => self.lexer.rest(),
+        }
+    }
+
+    fn peek(&mut self) -> Option<&(Lexeme, Span)> {
+        if self.peeked.is_none() {
+            self.peeked = self.lexer.next_lexeme();
+        }
+
+        self.peeked.as_ref()
+    }
+
+    fn next(&mut self) -> Option<(Lexeme, Span)> {
+        self.peek();
+        self.peeked.take()
+    }
+
+    fn error(&mut self, span: Span, message: &str) {
+        let error = ParseError::at(self.lexer.source(), span.start, message);
+        self.errors.push(error);
+    }
+
+    fn end_of_input(&self) -> Span {
+        let source = self.lexer.source();
+        Span::new(source, source.len(), source.len())
+    }
+
+    // @document <name> <items>...
+    pub fn document(&mut self) -> Token {
+        let mut name = Token::String("".to_owned());
+
+        match self.peek().cloned() {
+            Some((Lexeme::Document, _)) => {
+                self.next();
+                match self.peek().cloned() {
+                    Some((Lexeme::Token(token @ (Token::String(_) | Token::Atom(_))), span)) => {
+                        self.next();
+                        name = Token::Spanned(span, Box::new(token));
+                    }
+                    Some((_, span)) => self.error(span, "expected document name"),
+                    None => {
+                        let span = self.end_of_input();
+                        self.error(span, "expected document name")
+                    }
+                }
+            }
+            Some((_, span)) => self.error(span, "expected @document"),
+            None => {
+                let span = self.end_of_input();
+                self.error(span, "expected @document")
+            }
+        }
+
+        let mut items = vec![];
+        while self.peek().is_some() {
+            if let Some(item) = self.item() {
+                items.push(item);
+            }
+        }
+
+        Token::Document(Box::new(name), Box::new(Token::List(items)))
+    }
+
+    // a single expression followed by the end of the input
+    pub fn line(&mut self) -> Token {
+        let mut value = None;
+        while value.is_none() && self.peek().is_some() {
+            value = self.item();
+        }
+
+        if let Some((_, span)) = self.peek().cloned() {
+            self.error(span, "unexpected input");
+            while self.next().is_some() {}
+        }
+
+        match value {
+            Some(value) => value,
+            None => {
+                let span = self.end_of_input();
+                self.error(span, "expected token");
+                Token::List(vec![])
+            }
+        }
+    }
+
+    // parse the next expression, `None` when the input is skipped
+    pub fn item(&mut self) -> Option<Token> {
+        let (lexeme, span) = self.next()?;
+
+        match lexeme {
+            Lexeme::Open => Some(self.list(span, Lexeme::Close)),
+            Lexeme::Begin => Some(self.list(span, Lexeme::End)),
+            Lexeme::Token(Token::KeywordArg(name, _)) => self.keyword_arg(span, name),
+            Lexeme::Token(token) => Some(Token::Spanned(span, Box::new(token))),
+            Lexeme::Close => {
+                self.error(span, "unexpected )");
+                None
+            }
+            Lexeme::End => {
+                self.error(span, "unexpected @end");
+                None
+            }
+            Lexeme::Document => {
+                self.error(span, "unexpected @document");
+                None
+            }
+            Lexeme::Error(message) => {
+                self.error(span, &message);
+                None
+            }
+        }
+    }
+
+    // `name: value`, the value is whatever expression follows the name
+    fn keyword_arg(&mut self, name_span: Span, name: String) -> Option<Token> {
+        match self.peek().cloned() {
+            Some((Lexeme::Close, span)) | Some((Lexeme::End, span)) => {
+                self.error(span, &format!("expected value after {}:", name));
+                None
+            }
+            None => {
+                let span = self.end_of_input();
+                self.error(span, &format!("expected value after {}:", name));
+                None
+            }
+            Some(_) => {
+                let value = self.item()?;
+                let span = match &value {
+                    Token::Spanned(span, _) => name_span.to(*span),
+                    _ => name_span,
+                };
+
+                Some(Token::Spanned(
+                    span,
+                    Box::new(Token::KeywordArg(name, Box::new(value))),
+                ))
+            }
+        }
+    }
+
+    fn list(&mut self, open: Span, close: Lexeme) -> Token {
+        let mut items = vec![];
+
+        loop {
+            match self.peek().cloned() {
+                None => {
+                    let message = match close {
+                        Lexeme::End => "unclosed @begin, expected @end",
+                        _ => "unclosed (, expected )",
+                    };
+                    self.error(open, message);
+                    let end = self.end_of_input();
+
+                    return Token::Spanned(open.to(end), Box::new(list_variant(items)));
+                }
+                Some((lexeme, span)) if lexeme == close => {
+                    self.next();
+
+                    return Token::Spanned(open.to(span), Box::new(list_variant(items)));
+                }
+                Some(_) => {
+                    if let Some(item) = self.item() {
+                        items.push(item);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+#[cfg(test)]
+use super::{document, error::ParseError, repl_line, token::Token};
+
+#[cfg(test)]
+fn errors(input: &str) -> Vec<(usize, String)> {
+    document(input)
+        .unwrap_err()
+        .into_iter()
+        .map(|err: ParseError| (err.offset(input), err.message))
+        .collect()
+}
+
+#[test]
+fn should_parse_begin_end_list() {
+    let input = "@document test\n@begin .println \"hello\" @end";
+
+    let res = document(input).unwrap();
+
+    assert_eq!(
+        Token::Document(
+            Box::new(Token::Atom("test".to_owned())),
+            Box::new(Token::List(vec![Token::List(vec![
+                Token::Identifier("println".to_owned()),
+                Token::String("hello".to_owned()),
+            ])]))
+        ),
+        res
+    )
+}
+
+#[test]
+fn should_parse_definition() {
+    let input = "(@def (.greet Name) (.println Name))";
+
+    let res = repl_line(input).unwrap();
+
+    assert_eq!(
+        Token::Definition(
+            Box::new(Token::List(vec![
+                Token::Identifier("greet".to_owned()),
+                Token::Variable("Name".to_owned(), None),
+            ])),
+            Box::new(Token::List(vec![
+                Token::Identifier("println".to_owned()),
+                Token::Variable("Name".to_owned(), None),
+            ]))
+        ),
+        res
+    )
+}
+
+#[test]
+fn should_parse_without_whitespace_between_lists() {
+    let input = "((a)(b))";
+
+    let res = repl_line(input).unwrap();
+
+    assert_eq!(
+        Token::List(vec![
+            Token::List(vec![Token::Atom("a".to_owned())]),
+            Token::List(vec![Token::Atom("b".to_owned())]),
+        ]),
+        res
+    )
+}
+
+#[test]
+fn error_on_unclosed_parentheses() {
+    let input = "@document test\n(a (b c)";
+
+    assert_eq!(
+        vec![(15, "unclosed (, expected )".to_owned())],
+        errors(input)
+    )
+}
+
+#[test]
+fn error_on_unterminated_string() {
+    let input = "@document test\n(.println \"hello)\n(a)";
+
+    assert_eq!(vec![(25, "unterminated string".to_owned())], errors(input))
+}
+
+#[test]
+fn error_on_stray_end() {
+    let input = "@document test\n(a) @end (b)";
+
+    assert_eq!(vec![(19, "unexpected @end".to_owned())], errors(input))
+}
+
+#[test]
+fn error_on_keyword_arg_without_value() {
+    let input = "@document test\n(.select Cols where:) (b)";
+
+    assert_eq!(
+        vec![(35, "expected value after where:".to_owned())],
+        errors(input)
+    )
+}
+
+#[test]
+fn should_report_every_error() {
+    let input = "@document test
+(a))
+(.println \"hello)
+@end
+(@func (.main))
+(b";
+
+    assert_eq!(
+        vec![
+            (18, "unexpected )".to_owned()),
+            (30, "unterminated string".to_owned()),
+            (38, "unexpected @end".to_owned()),
+            (44, "unknown keyword @func".to_owned()),
+            (59, "unclosed (, expected )".to_owned()),
+        ],
+        errors(input)
+    )
+}
+
+#[test]
+fn error_on_missing_document() {
+    let input = "(a)";
+
+    assert_eq!(vec![(0, "expected @document".to_owned())], errors(input))
+}
@@ -0,0 +1,84 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+// position of a token in the source, `start` and `end` are byte offsets,
+// `line` and `column` are 1-based and point at `start`, `source` tells
+// which text the offsets are into
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Span {
+    pub source: u64,
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Span {
+    pub fn new(source: &str, start: usize, end: usize) -> Self {
+        let start = floor(source, start);
+        let end = ceil(source, end.max(start));
+        let before = &source[..start];
+        let line = before.matches('\n').count() + 1;
+        let column = match before.rfind('\n') {
+            Some(i) => before[i + 1..].chars().count() + 1,
+            None => before.chars().count() + 1,
+        };
+
+        Self {
+            source: Span::id(source),
+            start,
+            end,
+            line,
+            column,
+        }
+    }
+
+    // identifies a source text, spans of two files never share it
+    pub fn id(source: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        source.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    // the part of `source` the span covers, `None` when the span was
+    // recorded in another source
+    pub fn text<'a>(&self, source: &'a str) -> Option<&'a str> {
+        match self.source == Span::id(source) {
+            true => source.get(self.start..self.end),
+            false => None,
+        }
+    }
+
+    // span covering both `self` and `other`
+    pub fn to(self, other: Span) -> Self {
+        Self {
+            end: other.end.max(self.end),
+            ..self
+        }
+    }
+
+    // the source line the span starts on
+    pub fn source_line<'a>(&self, source: &'a str) -> &'a str {
+        source.lines().nth(self.line - 1).unwrap_or("")
+    }
+}
+
+// closest char boundary at or before `offset`
+fn floor(source: &str, offset: usize) -> usize {
+    let mut offset = offset.min(source.len());
+    while !source.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    offset
+}
+
+// closest char boundary at or after `offset`
+fn ceil(source: &str, offset: usize) -> usize {
+    let mut offset = offset.min(source.len());
+    while !source.is_char_boundary(offset) {
+        offset += 1;
+    }
+
+    offset
+}
@@ -0,0 +1,67 @@
+#[cfg(test)]
+use super::{document, repl_line, span::Span, token::Token};
+
+#[test]
+fn should_record_line_and_column() {
+    let input = "@document test\n(hello)\n  (hello world)";
+
+    let res = document(input).unwrap();
+
+    let spans: Vec<Span> = match res {
+        Token::Document(_, content) => match *content {
+            Token::List(list) => list
+                .iter()
+                .map(|token| match token {
+                    Token::Spanned(span, _) => *span,
+                    _ => panic!("missing span"),
+                })
+                .collect(),
+            _ => panic!("invalid document"),
+        },
+        _ => panic!("invalid document"),
+    };
+
+    assert_eq!(
+        vec![(15, 22, 2, 1), (25, 38, 3, 3)],
+        spans
+            .iter()
+            .map(|span| (span.start, span.end, span.line, span.column))
+            .collect::<Vec<_>>()
+    )
+}
+
+#[test]
+fn should_record_nested_spans() {
+    let input = "(+ 1 (+ 2 3))";
+
+    let res = repl_line(input).unwrap();
+
+    match res {
+        Token::Spanned(span, list) => {
+            assert_eq!(Span::new(input, 0, 13), span);
+            match *list {
+                Token::List(list) => match &list[2] {
+                    Token::Spanned(span, _) => assert_eq!(Span::new(input, 5, 12), *span),
+                    _ => panic!("missing span"),
+                },
+                _ => panic!("invalid list"),
+            }
+        }
+        _ => panic!("missing span"),
+    }
+}
+
+#[test]
+fn should_ignore_spans_when_comparing() {
+    let input = "(hello world)";
+
+    let res = repl_line(input).unwrap();
+
+    assert_eq!(
+        Token::List(vec![
+            Token::Atom("hello".to_owned()),
+            Token::Atom("world".to_owned())
+        ]),
+        res
+    )
+}
@@ -1,24 +1,114 @@
+use super::error::IResult;
+use super::lexer::{Lexer, Position, Scan};
 use super::token::Token;
-use nom::{
-    branch::alt,
-    bytes::complete::{is_not, tag},
-    multi::many0,
-    sequence::tuple,
-    IResult,
-};
-
-pub fn string(input: &str) -> IResult<&str, Token, ()> {
-    let (input, (_, value, _)) = alt((
-        tuple((tag("\""), many0(is_not("\"\n\r")), tag("\""))),
-        tuple((tag("\'"), many0(is_not("\'\n\r")), tag("\'"))),
-    ))(input)?;
-
-    let res: String = value.into_iter().fold("".to_owned(), |acc, item| {
-        let mut ret = acc;
-        ret += item;
-
-        ret
-    });
-
-    Ok((&input, Token::String(res)))
+
+impl<'a> Lexer<'a> {
+    // `"..."` or `'...'` on a single line, `"""..."""` or `'''...'''` over
+    // any number of lines, escapes are decoded unless prefixed with `r`
+    pub fn string(&mut self) -> Scan {
+        let raw = self.peek()? == 'r' && matches!(self.peek_nth(1), Some('"' | '\''));
+        if raw {
+            self.bump();
+        }
+
+        let quote = self.peek()?;
+        if quote != '"' && quote != '\'' {
+            return None;
+        }
+
+        let triple = quote.to_string().repeat(3);
+        if self.starts_with(&triple) {
+            self.bump_str(&triple);
+            return Some(self.string_body(&triple, raw, true));
+        }
+
+        self.bump();
+        Some(self.string_body(&quote.to_string(), raw, false))
+    }
+
+    // content of a string up to the closing `end`
+    fn string_body(&mut self, end: &str, raw: bool, multiline: bool) -> Result<Token, String> {
+        let start = self.position();
+        let mut value = String::new();
+        let mut error = None;
+
+        loop {
+            if self.starts_with(end) {
+                self.bump_str(end);
+                break;
+            }
+
+            match self.peek() {
+                None => return self.unterminated(start),
+                Some('\n' | '\r') if !multiline => return self.unterminated(start),
+                Some('\\') if !raw => {
+                    self.bump();
+                    match self.escape() {
+                        Ok(c) => value.push(c),
+                        // keep going so the rest of the string is skipped
+                        Err(message) => {
+                            error.get_or_insert(message);
+                        }
+                    }
+                }
+                Some(c) => {
+                    self.bump();
+                    value.push(c);
+                }
+            }
+        }
+
+        match error {
+            Some(message) => Err(message),
+            None => Ok(Token::String(value)),
+        }
+    }
+
+    fn unterminated(&mut self, start: Position) -> Result<Token, String> {
+        // only skip the quote so the rest of the line is still parsed
+        self.reset(start);
+        Err("unterminated string".to_owned())
+    }
+
+    // character of the escape after a `\`
+    fn escape(&mut self) -> Result<char, String> {
+        match self.peek() {
+            Some('\n' | '\r') | None => return Err("unterminated escape".to_owned()),
+            _ => (),
+        }
+
+        match self.bump() {
+            Some('n') => Ok('\n'),
+            Some('t') => Ok('\t'),
+            Some('r') => Ok('\r'),
+            Some('0') => Ok('\0'),
+            Some(c @ ('\\' | '"' | '\'')) => Ok(c),
+            Some('u') => self.unicode_escape(),
+            Some(c) => Err(format!("unknown escape \\{}", c)),
+            None => Err("unterminated escape".to_owned()),
+        }
+    }
+
+    // `\u{1F600}`, one to six hexadecimal digits
+    fn unicode_escape(&mut self) -> Result<char, String> {
+        if self.peek() != Some('{') {
+            return Err("expected { after \\u".to_owned());
+        }
+        self.bump();
+
+        let digits = self.eat_while(|c| c.is_ascii_hexdigit());
+        if self.peek() != Some('}') || digits.is_empty() || digits.len() > 6 {
+            return Err("invalid unicode escape".to_owned());
+        }
+        self.bump();
+
+        u32::from_str_radix(digits, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| format!("invalid unicode escape \\u{{{}}}", digits))
+    }
+}
+
+pub fn string(input: &str) -> IResult<&str, Token> {
+    Lexer::scan(input, "string", Lexer::string)
 }
@@ -1,7 +1,7 @@
 #[cfg(test)]
-use super::{string::string, token::Token};
+use super::error::{Err, IResult};
 #[cfg(test)]
-use nom::{Err, IResult};
+use super::{error::ParseError, string::string, token::Token};
 
 #[test]
 fn should_be_valid_double_quoted_string() {
@@ -36,7 +36,10 @@ fn error_on_missing_quote() {
 
     let res = string(input);
 
-    assert_eq!(IResult::Err(Err::Error(())), res)
+    assert_eq!(
+        IResult::Err(Err::Error(ParseError::new(input, "string"))),
+        res
+    )
 }
 
 #[test]
@@ -45,5 +48,98 @@ fn error_on_newline() {
 
     let res = string(input);
 
-    assert_eq!(IResult::Err(Err::Error(())), res)
+    assert_eq!(
+        IResult::Err(Err::Error(ParseError::new(input, "string"))),
+        res
+    )
+}
+
+#[test]
+fn should_decode_escapes() {
+    let input = r#""a\"b\\c\n\t\r\0'""#;
+
+    let res = string(input);
+
+    assert_eq!(Ok(("", Token::String("a\"b\\c\n\t\r\0'".to_owned()))), res)
+}
+
+#[test]
+fn should_decode_unicode_escapes() {
+    let input = r#""\u{e9}\u{1F600}""#;
+
+    let res = string(input);
+
+    assert_eq!(Ok(("", Token::String("é😀".to_owned()))), res)
+}
+
+#[test]
+fn should_be_valid_triple_quoted_string() {
+    let input = "\"\"\"first \"line\"\nsecond\\tline\"\"\"";
+
+    let res = string(input);
+
+    assert_eq!(
+        Ok(("", Token::String("first \"line\"\nsecond\tline".to_owned()))),
+        res
+    )
+}
+
+#[test]
+fn should_keep_raw_strings_as_written() {
+    assert_eq!(
+        Ok(("", Token::String("a\\nb".to_owned()))),
+        string(r#"r"a\nb""#)
+    );
+    assert_eq!(
+        Ok(("", Token::String("C:\\dir\n'x' y".to_owned()))),
+        string("r'''C:\\dir\n'x' y'''")
+    );
+}
+
+#[test]
+fn should_round_trip_through_display() {
+    let value = Token::String("say \"hi\"\n\\ \u{1}".to_owned());
+
+    let input = value.to_string();
+
+    let res = string(&input);
+
+    assert_eq!(Ok(("", value)), res)
+}
+
+#[test]
+fn error_on_unknown_escape() {
+    let input = "@document test\n(.println \"a\\qb\")";
+
+    let res = super::document(input)
+        .unwrap_err()
+        .into_iter()
+        .map(|err| (err.offset(input), err.message))
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec![(25, "unknown escape \\q".to_owned())], res)
+}
+
+#[test]
+fn error_on_invalid_unicode_escape() {
+    let input = r#""\u{110000}""#;
+
+    let res = string(input);
+
+    assert_eq!(
+        IResult::Err(Err::Error(ParseError::new(input, "string"))),
+        res
+    )
+}
+
+#[test]
+fn error_on_unterminated_triple_quoted_string() {
+    let input = "\"\"\"hello\nworld\"";
+
+    let res = string(input);
+
+    assert_eq!(
+        IResult::Err(Err::Error(ParseError::new(input, "string"))),
+        res
+    )
 }
@@ -1,4 +1,7 @@
+use super::span::Span;
+use crate::closure::Closure;
 use crate::utils::posibility::Posibility;
+use std::rc::Rc;
 
 #[derive(Clone, Debug)]
 pub enum Token {
@@ -8,11 +11,13 @@ pub enum Token {
     RParent,
     Whitespace,
     Comment,
+    Spanned(Span, Box<Token>),
 
     // types
     Value,
     String(String),
     Number(f64),
+    Integer(i64),
     Atom(String),
     Operator(String),
     Keyword(String),
@@ -20,12 +25,18 @@ pub enum Token {
     Variable(String, Option<Posibility<Token>>),
     Wildcard(String, Option<Posibility<Token>>),
     Boolean(bool),
+    // `name: value` argument of a list
+    KeywordArg(String, Box<Token>),
+    // function made by `@fn`, shared so copies stay the same function
+    Closure(Rc<Closure>),
 
     // list variant
     List(Vec<Token>),
     Definition(Box<Token>, Box<Token>),
     UnaryOperation(Box<Token>, Box<Token>),
     BinaryOperation(Box<Token>, Box<Token>, Box<Token>),
+    // signature of a variadic call, the head and its leading operands
+    // followed by any number of further operands
     ListOperation(Box<Token>, Vec<Token>),
 }
 
@@ -37,17 +48,84 @@ impl Token {
     pub fn _false() -> Self {
         Token::Boolean(false)
     }
+
+    // name of the kind of value, as shown to users
+    pub fn kind(&self) -> &'static str {
+        match self.inner() {
+            Token::String(_) => "string",
+            Token::Number(_) => "number",
+            Token::Integer(_) => "integer",
+            Token::Atom(_) => "atom",
+            Token::Boolean(_) => "boolean",
+            Token::Operator(_) => "operator",
+            Token::Keyword(_) => "keyword",
+            Token::Identifier(_) => "identifier",
+            Token::Variable(_, _) => "variable",
+            Token::Wildcard(_, _) => "wildcard",
+            Token::KeywordArg(_, _) => "keyword argument",
+            Token::Closure(_) => "closure",
+            Token::List(_) => "list",
+            Token::Definition(_, _) => "definition",
+            Token::Document(_, _) => "document",
+            _ => "value",
+        }
+    }
+
+    // the token without its source position
+    pub fn inner(&self) -> &Token {
+        match self {
+            Token::Spanned(_, token) => token.inner(),
+            token => token,
+        }
+    }
+
+    // value of the `name:` argument of a list
+    pub fn named(&self, name: &str) -> Option<&Token> {
+        match self.inner() {
+            Token::List(list) => list.iter().find_map(|item| match item.inner() {
+                Token::KeywordArg(key, value) if key == name => Some(value.as_ref()),
+                _ => None,
+            }),
+            _ => None,
+        }
+    }
+
+    // strip source positions from the token and everything it contains
+    pub fn unspanned(self) -> Token {
+        match self {
+            Token::Spanned(_, token) => token.unspanned(),
+            Token::List(list) => Token::List(list.into_iter().map(Token::unspanned).collect()),
+            Token::Document(name, content) => {
+                Token::Document(Box::new(name.unspanned()), Box::new(content.unspanned()))
+            }
+            Token::Definition(a, b) => {
+                Token::Definition(Box::new(a.unspanned()), Box::new(b.unspanned()))
+            }
+            Token::KeywordArg(name, value) => Token::KeywordArg(name, Box::new(value.unspanned())),
+            token => token,
+        }
+    }
 }
 
 impl PartialEq for Token {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
+            (Token::Spanned(_, a), b) => a.as_ref() == b,
+            (a, Token::Spanned(_, b)) => a == b.as_ref(),
+
             (Token::Document(_, a), Token::Document(_, b)) => a == b,
             (Token::Document(_, _), _) | (_, Token::Document(_, _)) => false,
             (Token::Definition(a, _), Token::Definition(b, _)) => a == b,
 
             (Token::Value, _) | (_, Token::Value) => true,
 
+            (Token::ListOperation(head, args), Token::List(list))
+            | (Token::List(list), Token::ListOperation(head, args)) => {
+                list.len() > args.len()
+                    && **head == list[0]
+                    && args.iter().zip(list[1..].iter()).all(|(x, y)| x == y)
+            }
+
             (Token::Comment, Token::Comment) | (Token::Whitespace, Token::Whitespace) => true,
             (_, Token::Comment) | (_, Token::Whitespace) => false,
 
@@ -59,15 +137,19 @@ impl PartialEq for Token {
             (Token::Identifier(_), _) | (_, Token::Identifier(_)) => false,
             (Token::Keyword(_), _) | (_, Token::Keyword(_)) => false,
 
+            (Token::KeywordArg(a, x), Token::KeywordArg(b, y)) => a == b && x == y,
             (Token::Number(a), Token::Number(b)) => a == b,
+            (Token::Integer(a), Token::Integer(b)) => a == b,
+            (Token::Integer(a), Token::Number(b)) | (Token::Number(b), Token::Integer(a)) => {
+                *a as f64 == *b
+            }
             (Token::Boolean(a), Token::Boolean(b)) => a == b,
+            (Token::Closure(a), Token::Closure(b)) => Rc::ptr_eq(a, b),
             (Token::Atom(a), Token::Atom(b)) => a == b,
             (Token::String(a), Token::String(b)) => a == b,
-            (Token::List(a), Token::List(b)) => a
-                .into_iter()
-                .zip(b.into_iter())
-                .map(|(x, y)| x == y)
-                .fold(true, |acc, val| acc && val),
+            (Token::List(a), Token::List(b)) => {
+                a.len() == b.len() && a.iter().zip(b.iter()).all(|(x, y)| x == y)
+            }
 
             (Token::Variable(_, None), Token::Variable(_, None)) => true,
             (_, Token::Variable(_, None)) | (Token::Variable(_, None), _) => true,
@@ -82,12 +164,31 @@ impl PartialEq for Token {
     }
 }
 
+// `s` as it is written in a double quoted string
+pub fn escape(s: &str) -> String {
+    let mut res = String::new();
+    for c in s.chars() {
+        match c {
+            '\\' => res.push_str("\\\\"),
+            '"' => res.push_str("\\\""),
+            '\n' => res.push_str("\\n"),
+            '\t' => res.push_str("\\t"),
+            '\r' => res.push_str("\\r"),
+            '\0' => res.push_str("\\0"),
+            c if c.is_control() => res.push_str(&format!("\\u{{{:x}}}", c as u32)),
+            c => res.push(c),
+        }
+    }
+
+    res
+}
+
 impl std::fmt::Display for Token {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Token::List(list) => {
                 write!(f, "(").unwrap();
-                for (i, token) in list.into_iter().enumerate() {
+                for (i, token) in list.iter().enumerate() {
                     if i > 0 {
                         write!(f, " ").unwrap();
                     }
@@ -95,6 +196,7 @@ impl std::fmt::Display for Token {
                 }
                 write!(f, ")")
             }
+            Token::Spanned(_, token) => token.fmt(f),
             Token::Boolean(b) => write!(f, "{}", b),
             Token::Variable(str, None) => write!(f, "{}", str),
             Token::Variable(str, Some(value)) => {
@@ -106,44 +208,33 @@ impl std::fmt::Display for Token {
             Token::Atom(str) | Token::Operator(str) => write!(f, "{}", str),
             Token::Keyword(str) => write!(f, "@{}", str),
             Token::Identifier(str) => write!(f, ".{}", str),
-            Token::String(str) => write!(f, "\"{}\"", str),
+            Token::KeywordArg(name, value) => write!(f, "{}: {}", name, value),
+            Token::String(str) => write!(f, "\"{}\"", escape(str)),
+            // whole floats keep a fraction so they read back as floats
+            Token::Number(n) if n.is_finite() && n.fract() == 0.0 => write!(f, "{:.1}", n),
             Token::Number(n) => write!(f, "{}", n),
+            Token::Integer(n) => write!(f, "{}", n),
             Token::Value => write!(f, "$"),
             Token::Whitespace => write!(f, "{{:whitespace}}"),
             Token::Comment => write!(f, "{{:comment}}"),
             Token::Document(name, content) => write!(f, "@document {} {}", name, content),
             Token::Definition(def, res) => write!(f, "(@def {} {})", def, res),
+            Token::Closure(closure) => {
+                write!(
+                    f,
+                    "(@fn {} {})",
+                    Token::List(closure.params.clone()),
+                    closure.body
+                )
+            }
+            Token::ListOperation(head, args) => {
+                write!(f, "({}", head).unwrap();
+                for arg in args.iter() {
+                    write!(f, " {}", arg).unwrap();
+                }
+                write!(f, " ...)")
+            }
             _ => write!(f, "{{:invalid}}"),
         }
     }
 }
-
-use super::atom::atom;
-use super::boolean::boolean;
-use super::identifier::identifier;
-use super::keyword::valid_keyword;
-use super::list::list;
-use super::number::number;
-use super::operator::operator;
-use super::string::string;
-use super::variable::variable;
-use super::wildcard::wildcard;
-use nom::{branch::alt, IResult};
-
-pub fn token(input: &str) -> IResult<&str, Token, ()> {
-    let res = alt((
-        list,
-        boolean,
-        string,
-        variable,
-        atom,
-        valid_keyword,
-        identifier,
-        number,
-        operator,
-        variable,
-        wildcard,
-    ))(input)?;
-
-    Ok(res)
-}
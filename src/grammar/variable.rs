@@ -1,19 +1,19 @@
+use super::error::IResult;
+use super::lexer::{Lexer, Scan, WORD};
 use super::token::Token;
-use nom::{
-    character::complete::one_of,
-    combinator::{opt, recognize},
-    multi::many1,
-    sequence::tuple,
-    IResult,
-};
 
-pub fn variable(input: &str) -> IResult<&str, Token, ()> {
-    let (input, value) = recognize(tuple((
-        one_of("ABCDEFGHIJKLMNOPQRSTUVWXYZ"),
-        opt(many1(one_of(
-            "_abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ1234567890",
-        ))),
-    )))(input)?;
+impl<'a> Lexer<'a> {
+    pub fn variable(&mut self) -> Scan {
+        if !self.peek()?.is_ascii_uppercase() {
+            return None;
+        }
 
-    Ok((&input, Token::Variable(value.to_owned(), None)))
+        let value = self.eat_while(|c| WORD.contains(c));
+
+        Some(Ok(Token::Variable(value.to_owned(), None)))
+    }
+}
+
+pub fn variable(input: &str) -> IResult<&str, Token> {
+    Lexer::scan(input, "variable", Lexer::variable)
 }
@@ -1,8 +1,17 @@
+use super::error::IResult;
+use super::lexer::{Lexer, Scan};
 use super::token::Token;
-use nom::{bytes::complete::is_a, IResult};
 
-pub fn whitespace(input: &str) -> IResult<&str, Token, ()> {
-    let (input, _value) = is_a("\t\n ")(input)?;
+impl<'a> Lexer<'a> {
+    pub fn whitespace(&mut self) -> Scan {
+        if self.eat_while(|c| " \t\n\r".contains(c)).is_empty() {
+            return None;
+        }
 
-    Ok((input, Token::Whitespace))
+        Some(Ok(Token::Whitespace))
+    }
+}
+
+pub fn whitespace(input: &str) -> IResult<&str, Token> {
+    Lexer::scan(input, "whitespace", Lexer::whitespace)
 }
@@ -1,18 +1,25 @@
+use super::error::IResult;
+use super::lexer::{Lexer, Scan, WORD};
 use super::token::Token;
-use nom::{
-    bytes::complete::{is_a, tag},
-    character::complete::one_of,
-    sequence::pair,
-    IResult,
-};
 
-pub fn wildcard(input: &str) -> IResult<&str, Token, ()> {
-    let (input, (_, value)) = pair(
-        tag("_"),
-        is_a("_abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ1234567890"),
-    )(input)?;
+impl<'a> Lexer<'a> {
+    pub fn wildcard(&mut self) -> Scan {
+        if self.peek()? != '_' {
+            return None;
+        }
 
-    let (_, _) = one_of("ABCDEFGHIJKLMNOPQRSTUVWXYZ")(value)?;
+        self.bump();
+        let value = self.eat_while(|c| WORD.contains(c));
+        if !value.starts_with(|c: char| c.is_ascii_uppercase()) {
+            return Some(Err(
+                "wildcard must start with an uppercase letter".to_owned()
+            ));
+        }
 
-    Ok((&input, Token::Wildcard(value.to_owned(), None)))
+        Some(Ok(Token::Wildcard(value.to_owned(), None)))
+    }
+}
+
+pub fn wildcard(input: &str) -> IResult<&str, Token> {
+    Lexer::scan(input, "wildcard", Lexer::wildcard)
 }
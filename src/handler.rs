@@ -1,7 +1,8 @@
+use crate::error::LexError;
 use crate::grammar::token::Token;
 use crate::state::State;
 
-pub type Handler = dyn Fn(&mut State, Token) -> Token;
+pub type Handler = dyn Fn(&mut State, Token) -> Result<Token, LexError>;
 
 // impl Handler {
 //     pub fn new(func: fn(&State, Token) -> Token) -> Self {
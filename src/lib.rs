@@ -0,0 +1,20 @@
+pub mod closure;
+pub mod datalog;
+pub mod datalog_tests;
+pub mod definition;
+pub mod error;
+pub mod evaluator;
+pub mod evaluator_tests;
+pub mod grammar;
+pub mod handler;
+pub mod modules;
+pub mod repl;
+pub mod solver;
+pub mod solver_tests;
+pub mod state;
+pub mod state_tests;
+pub mod stdlib;
+pub mod table;
+pub mod table_tests;
+pub mod test_support;
+pub mod utils;
@@ -1,14 +1,5 @@
-pub mod definition;
-pub mod evaluator;
-pub mod grammar;
-pub mod handler;
-pub mod modules;
-pub mod repl;
-pub mod state;
-pub mod stdlib;
-pub mod utils;
-
 use clap::{Parser, Subcommand};
+use lex::{error, evaluator, repl};
 use std::fs;
 
 #[derive(Parser, Debug)]
@@ -28,6 +19,12 @@ enum Command {
         /// .lx source file
         #[clap(parse(from_os_str))]
         path: std::path::PathBuf,
+        /// answer queries by bottom-up datalog evaluation
+        #[clap(long)]
+        datalog: bool,
+        /// arguments given to the program, after `--`
+        #[clap(last = true)]
+        args: Vec<String>,
     },
     /// run interactive mode
     Repl,
@@ -38,11 +35,35 @@ fn main() {
 
     match args.command {
         Command::Repl => repl::Repl::new().run(),
-        Command::Run { ref path } => {
-            let input = fs::read_to_string(path).unwrap();
-            let res = evaluator::Engine::new().parse(&input).unwrap();
+        Command::Run {
+            ref path,
+            datalog,
+            ref args,
+        } => {
+            let file = path.to_string_lossy();
+            let input = match fs::read_to_string(path) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("error: {}", error::LexError::io(&file, err));
+                    std::process::exit(1);
+                }
+            };
+
+            let mut engine = evaluator::Engine::new();
+            engine.set_datalog(datalog);
+            engine.set_args(args.clone());
+
+            match engine.parse(&input) {
+                Ok(res) => println!("exited: {}", res),
+                Err(err) => {
+                    if let Some(code) = err.exit_code() {
+                        std::process::exit(code);
+                    }
 
-            println!("exited: {}", res)
+                    eprintln!("{}", err.render(&file, &input));
+                    std::process::exit(1);
+                }
+            }
         }
     }
 }
@@ -2,6 +2,11 @@ use crate::definition::Definition;
 
 pub trait Module {
     fn load(&self) -> Vec<Definition>;
+
+    // capability an engine must allow for the module to be included
+    fn capability(&self) -> Option<&'static str> {
+        None
+    }
 }
 
 impl Module for &dyn Module {
@@ -9,4 +14,9 @@ impl Module for &dyn Module {
         let m = *self;
         m.load()
     }
+
+    fn capability(&self) -> Option<&'static str> {
+        let m = *self;
+        m.capability()
+    }
 }
@@ -6,6 +6,12 @@ pub struct Repl {
     engine: Engine,
 }
 
+impl Default for Repl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Repl {
     pub fn new() -> Self {
         Self {
@@ -23,9 +29,21 @@ impl Repl {
             match readline {
                 Ok(line) => {
                     rl.add_history_entry(line.as_str());
+                    if line.trim() == ":clear-tables" {
+                        self.engine.clear_tables();
+                        println!("=> tables cleared");
+                        continue;
+                    }
+
                     match self.engine.parse_line(&line) {
                         Ok(token) => println!("=> {}", token),
-                        Err(_) => (),
+                        Err(err) => match err.exit_code() {
+                            Some(code) => {
+                                rl.save_history("history.txt").unwrap();
+                                std::process::exit(code);
+                            }
+                            None => println!("{}", err.render("<repl>", &line)),
+                        },
                     }
                 }
                 Err(ReadlineError::Interrupted) => {
@@ -0,0 +1,266 @@
+use crate::datalog::{Database, Datalog};
+use crate::error::LexError;
+use crate::grammar::token::Token;
+use crate::state::State;
+use crate::table::Lookup;
+use crate::utils::unify::{self, Substitution};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+// called with every substitution proving a goal, returning false stops the
+// search
+pub type Found<'a> = dyn FnMut(&Substitution) -> bool + 'a;
+
+// depth first, backtracking resolution of goals against the `@dec` facts
+// and `@def` rules visible from `state`, in datalog mode goals of relations
+// of the program are looked up in the materialized relations instead
+pub struct Solver {
+    state: State,
+    database: RefCell<Option<Rc<Database>>>,
+}
+
+impl Solver {
+    pub fn new(state: State) -> Self {
+        Self {
+            state,
+            database: RefCell::new(None),
+        }
+    }
+
+    // relations of the program, computed once per solver
+    fn database(&self) -> Result<Option<Rc<Database>>, LexError> {
+        if !self.state.datalog() {
+            return Ok(None);
+        }
+
+        if let Some(db) = self.database.borrow().as_ref() {
+            return Ok(Some(Rc::clone(db)));
+        }
+
+        let db = Rc::new(Datalog::new(&self.state)?.run()?);
+        *self.database.borrow_mut() = Some(Rc::clone(&db));
+
+        Ok(Some(db))
+    }
+
+    // every solution of `goal`, each binding the variables of the goal
+    pub fn all(&self, goal: &Token) -> Result<Vec<Substitution>, LexError> {
+        let names = unify::variables(goal);
+        let mut res = vec![];
+
+        self.solve(goal, &mut |subst| {
+            let mut answer = Substitution::new();
+            for name in names.iter() {
+                let value = subst.resolve(unify::variable(name));
+                answer.bind(name, value.unspanned());
+            }
+
+            res.push(answer);
+            true
+        })?;
+
+        Ok(res)
+    }
+
+    // whether `goal` has at least one solution
+    pub fn any(&self, goal: &Token) -> Result<bool, LexError> {
+        let mut found = false;
+        self.solve(goal, &mut |_| {
+            found = true;
+            false
+        })?;
+
+        Ok(found)
+    }
+
+    // returns false if the search was stopped by `found`
+    pub fn solve(&self, goal: &Token, found: &mut Found) -> Result<bool, LexError> {
+        self.prove(vec![goal.clone()], &Substitution::new(), found)
+    }
+
+    fn prove(
+        &self,
+        mut goals: Vec<Token>,
+        subst: &Substitution,
+        found: &mut Found,
+    ) -> Result<bool, LexError> {
+        if goals.is_empty() {
+            return Ok(found(subst));
+        }
+
+        let goal = subst.walk(&goals.remove(0)).clone();
+        if let Some((a, b)) = unify::equation(&goal) {
+            let mut next = subst.clone();
+            return match next.unify(a, b) {
+                true => self.prove(goals, &next, found),
+                false => Ok(true),
+            };
+        }
+
+        match &goal {
+            Token::Boolean(true) => self.prove(goals, subst, found),
+            Token::Boolean(false) => Ok(true),
+            Token::List(list) => match list.first().map(Token::inner) {
+                // conjunction, prove every operand in order
+                Some(Token::Keyword(key)) if key == "and" => {
+                    let operands = list[1..].iter().cloned();
+                    self.prove(operands.chain(goals).collect(), subst, found)
+                }
+                // disjunction, try every operand in order
+                Some(Token::Keyword(key)) if key == "or" => {
+                    for operand in list[1..].iter() {
+                        let mut next = vec![operand.clone()];
+                        next.extend(goals.iter().cloned());
+
+                        if !self.prove(next, subst, found)? {
+                            return Ok(false);
+                        }
+                    }
+
+                    Ok(true)
+                }
+                // negation as failure, holds only if the operand has no solution
+                Some(Token::Operator(op)) if op == "!" && list.len() == 2 => {
+                    match self.any(&subst.resolve(list[1].clone()))? {
+                        true => Ok(true),
+                        false => self.prove(goals, subst, found),
+                    }
+                }
+                Some(Token::Operator(op)) if op == "?" && list.len() == 2 => {
+                    goals.insert(0, list[1].clone());
+                    self.prove(goals, subst, found)
+                }
+                _ => self.resolve(goal, goals, subst, found),
+            },
+            _ => self.resolve(goal, goals, subst, found),
+        }
+    }
+
+    // prove `goal` with every clause whose head unifies with it, or by
+    // evaluating it when only builtin definitions match
+    fn resolve(
+        &self,
+        goal: Token,
+        goals: Vec<Token>,
+        subst: &Substitution,
+        found: &mut Found,
+    ) -> Result<bool, LexError> {
+        let goal = subst.resolve(goal);
+        if let Some(db) = self.database()? {
+            if let Some(facts) = db.get(&goal) {
+                for fact in facts {
+                    let mut next = subst.clone();
+                    if next.unify(&goal, fact) && !self.prove(goals.clone(), &next, found)? {
+                        return Ok(false);
+                    }
+                }
+
+                return Ok(true);
+            }
+        }
+
+        if self.state.tables().is_tabled(&goal) {
+            for answer in self.tabled(&goal)? {
+                let mut next = subst.clone();
+                if next.unify(&goal, &answer) && !self.prove(goals.clone(), &next, found)? {
+                    return Ok(false);
+                }
+            }
+
+            return Ok(true);
+        }
+
+        self.expand(goal, goals, subst, found)
+    }
+
+    // answers of a call to a tabled relation, the first call of a pattern
+    // leads an evaluation that is repeated until no table grows, calls made
+    // during it only see the answers found so far, which is what makes left
+    // recursion terminate
+    fn tabled(&self, goal: &Token) -> Result<Vec<Token>, LexError> {
+        let scope = self.state.scope_id();
+        let lookup = self.state.tables().lookup(scope, goal);
+        match lookup {
+            Lookup::Answers(answers) => return Ok(answers),
+            Lookup::Evaluate => self.fill(goal)?,
+            Lookup::Lead => {
+                let res = self.lead(goal);
+                self.state.tables().finish(res.is_ok());
+                res?
+            }
+        }
+
+        Ok(self.state.tables().answers(scope, goal))
+    }
+
+    fn lead(&self, goal: &Token) -> Result<(), LexError> {
+        let scope = self.state.scope_id();
+        loop {
+            self.state.tables().next_round();
+            self.state.tables().lookup(scope, goal);
+            self.fill(goal)?;
+
+            if !self.state.tables().changed() {
+                return Ok(());
+            }
+        }
+    }
+
+    // add every answer the clauses give for `goal` to its table
+    fn fill(&self, goal: &Token) -> Result<(), LexError> {
+        let state = self.state.clone();
+        let scope = state.scope_id();
+        self.expand(goal.clone(), vec![], &Substitution::new(), &mut |subst| {
+            let answer = subst.resolve(goal.clone()).unspanned();
+            state.tables().answer(scope, goal, answer);
+            true
+        })?;
+
+        Ok(())
+    }
+
+    fn expand(
+        &self,
+        goal: Token,
+        goals: Vec<Token>,
+        subst: &Substitution,
+        found: &mut Found,
+    ) -> Result<bool, LexError> {
+        let (clauses, builtins): (Vec<_>, Vec<_>) = self
+            .state
+            .find_all(goal.clone())
+            .into_iter()
+            .partition(|def| def.is_clause());
+
+        if clauses.is_empty() {
+            if builtins.is_empty() {
+                return Ok(true);
+            }
+
+            return match self.state.clone().exec(goal)?.inner() {
+                Token::Boolean(false) => Ok(true),
+                _ => self.prove(goals, subst, found),
+            };
+        }
+
+        for clause in clauses {
+            // every use of a clause gets its own variables
+            let suffix = unify::fresh();
+            let head = unify::rename(clause.inp_sig.clone(), &suffix);
+            let body = unify::rename(clause.out_sig.clone(), &suffix);
+
+            let mut next = subst.clone();
+            if !next.unify(&head, &goal) {
+                continue;
+            }
+
+            let mut goals = goals.clone();
+            goals.insert(0, body);
+            if !self.prove(goals, &next, found)? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
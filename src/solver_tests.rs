@@ -0,0 +1,174 @@
+#[cfg(test)]
+use super::{
+    evaluator::Engine,
+    grammar::token::Token,
+    test_support::{answers, atoms, engine},
+    utils::unify::Substitution,
+};
+
+#[cfg(test)]
+const GRAPH: &str = r#"
+@document graph
+(@dec (a .to b))
+(@dec (a .to e))
+(@dec (b .to c))
+(@dec (c .to d))
+(@def (X .hop Z) (@and (X .to Y) (Y .to Z)))
+(@def (X .near Y) (@or (X .to Y) (X .hop Y)))
+(@def (X .path Y) (X .to Y))
+(@def (X .path Z) (@and (X .to Y) (Y .path Z)))
+(@def (X .stuck Y) false)
+"#;
+
+#[test]
+fn should_enumerate_matching_facts() {
+    let res = answers(&mut engine(GRAPH), "(a .to X)", "X");
+
+    assert_eq!(atoms(&["b", "e"]), res)
+}
+
+#[test]
+fn should_bind_wildcards() {
+    let res = answers(&mut engine(GRAPH), "(_From .to d)", "_From");
+
+    assert_eq!(atoms(&["c"]), res)
+}
+
+#[test]
+fn should_prove_conjunctions() {
+    let res = answers(&mut engine(GRAPH), "(a .hop X)", "X");
+
+    assert_eq!(atoms(&["c"]), res)
+}
+
+#[test]
+fn should_prove_disjunctions() {
+    let res = answers(&mut engine(GRAPH), "(a .near X)", "X");
+
+    assert_eq!(atoms(&["b", "e", "c"]), res)
+}
+
+#[test]
+fn should_backtrack_through_recursive_rules() {
+    let res = answers(&mut engine(GRAPH), "(a .path X)", "X");
+
+    assert_eq!(atoms(&["b", "e", "c", "d"]), res)
+}
+
+#[test]
+fn should_fail_on_false_body() {
+    let res = engine(GRAPH).query("(a .stuck X)").unwrap();
+
+    assert!(res.is_empty())
+}
+
+#[test]
+fn should_prove_ground_goal_once() {
+    let res = engine(GRAPH).query("(a .hop c)").unwrap();
+
+    assert_eq!(vec![Substitution::new()], res)
+}
+
+#[cfg(test)]
+fn eval(engine: &mut Engine, line: &str) -> Token {
+    engine.parse_line(line).unwrap().unspanned()
+}
+
+#[test]
+fn should_query_ground_goal() {
+    let mut engine = engine(GRAPH);
+
+    assert_eq!(Token::_true(), eval(&mut engine, "(? (a .hop c))"));
+    assert_eq!(Token::_false(), eval(&mut engine, "(? (a .hop d))"));
+}
+
+#[test]
+fn should_list_solutions_of_query() {
+    let res = eval(&mut engine(GRAPH), "(? (a .to _Somewhere))");
+
+    let solution = |to: &str| {
+        Token::List(vec![Token::KeywordArg(
+            "_Somewhere".to_owned(),
+            Box::new(Token::Atom(to.to_owned())),
+        )])
+    };
+    assert_eq!(Token::List(vec![solution("b"), solution("e")]), res)
+}
+
+#[test]
+fn should_keep_solutions_as_data_when_printed() {
+    let mut engine = engine(GRAPH);
+    engine.parse_line("(@include \"fmt\")").unwrap();
+    engine.parse_line("(@def (kind Y) false)").unwrap();
+
+    let res = eval(&mut engine, "(.println (? (a .to X)))");
+
+    let solution = |to: &str| {
+        Token::List(vec![Token::KeywordArg(
+            "X".to_owned(),
+            Box::new(Token::Atom(to.to_owned())),
+        )])
+    };
+    assert_eq!(Token::List(vec![solution("b"), solution("e")]), res)
+}
+
+#[test]
+fn should_not_run_calls_stored_in_solutions() {
+    let mut engine = engine(GRAPH);
+    engine.parse_line("(@include \"fmt\")").unwrap();
+    engine.parse_line("(@include \"os\")").unwrap();
+    engine.parse_line("(@dec (cmd (.exit 3)))").unwrap();
+
+    let res = engine.parse_line("(.println (? (cmd X)))");
+
+    let exit = Token::List(vec![
+        Token::Identifier("exit".to_owned()),
+        Token::Integer(3),
+    ]);
+    assert_eq!(
+        Ok(Token::List(vec![Token::List(vec![Token::KeywordArg(
+            "X".to_owned(),
+            Box::new(exit),
+        )])])),
+        res.map(Token::unspanned)
+    )
+}
+
+#[test]
+fn should_list_no_solutions_of_unprovable_query() {
+    let res = eval(&mut engine(GRAPH), "(? (d .to _Somewhere))");
+
+    assert_eq!(Token::List(vec![]), res)
+}
+
+#[test]
+fn should_negate_as_failure() {
+    let mut engine = engine(GRAPH);
+
+    assert_eq!(Token::_false(), eval(&mut engine, "(! (a .to _Somewhere))"));
+    assert_eq!(Token::_true(), eval(&mut engine, "(! (d .to _Somewhere))"));
+    assert_eq!(Token::_true(), eval(&mut engine, "(! (a .to d))"));
+}
+
+#[test]
+fn should_negate_inside_rules() {
+    let mut engine = engine(GRAPH);
+    engine
+        .parse_line("(@def (X .leaf) (@and (_From .to X) (! (X .to _To))))")
+        .unwrap();
+
+    let res = answers(&mut engine, "(X .leaf)", "X");
+
+    assert_eq!(atoms(&["e", "d"]), res)
+}
+
+#[test]
+fn should_match_integral_floats_and_integers() {
+    let mut engine = Engine::new();
+    engine.parse_line("(@dec (id 1))").unwrap();
+    engine.parse_line("(@dec (fid 2.0))").unwrap();
+
+    assert_eq!(Ok(Token::_true()), engine.parse_line("(? (id 1.0))"));
+    assert_eq!(Ok(Token::_true()), engine.parse_line("(? (fid 2))"));
+    assert_eq!(Ok(Token::_false()), engine.parse_line("(? (fid 2.5))"));
+}
@@ -1,88 +1,352 @@
 use crate::definition::Definition;
+use crate::error::LexError;
 use crate::grammar::token::Token;
 use crate::modules::Module;
-use crate::utils::arraymap::ArrayMap;
-use crate::utils::posibility::Posibility;
-use std::collections::HashMap;
+use crate::table::Tables;
+use crate::utils::trie::Trie;
+use crate::utils::unify::Substitution;
+use std::cell::{RefCell, RefMut};
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
 
 // for debugging and error tracing
-static mut DEPTH: i32 = 1;
-static mut DEBUG: bool = false;
+static DEPTH: AtomicI32 = AtomicI32::new(1);
+static DEBUG: AtomicBool = AtomicBool::new(false);
 
-#[derive(Clone, Debug)]
-pub struct State {
-    parent: Option<*mut Self>,
-    definitions: ArrayMap<Token, Definition>,
+// keyword forms whose operands are passed to their definition unevaluated
+const SPECIAL_FORMS: &[&str] = &["and", "or", "if", "cond", "match", "let", "fn", "table"];
+
+// the query operators and `.select` take goals, which are not evaluated
+// either, a user function named like a keyword is called like any other
+fn special_form(list: &[Token]) -> bool {
+    match list.first().map(Token::inner) {
+        Some(Token::Keyword(head)) => SPECIAL_FORMS.contains(&head.as_str()),
+        Some(Token::Operator(head)) => head == "?" || head == "!",
+        Some(Token::Identifier(head)) => head == "select",
+        _ => false,
+    }
+}
+
+// a single environment frame, frames are shared through `Rc` so a scope
+// stays alive for as long as any state (or closure) refers to it
+#[derive(Debug, Default)]
+pub struct Scope {
+    parent: Option<Rc<RefCell<Scope>>>,
+    definitions: Trie,
     return_value: Option<Token>,
+    // values bound by `@let` in this frame
     variables: HashMap<String, Token>,
-    wildcards: HashMap<String, Posibility<Token>>,
+}
+
+// settings shared by every scope of an engine
+#[derive(Debug, Default)]
+pub struct Session {
+    // answer queries bottom up from the materialized relations
+    datalog: bool,
+    tables: Tables,
+    // capabilities turned off, e.g. `io` for embedded engines
+    denied: HashSet<String>,
+    // command line arguments given to the program
+    args: Vec<String>,
+}
+
+// handle on the innermost scope of a scope chain, cloning a state gives
+// another handle on the same scope
+#[derive(Clone, Debug, Default)]
+pub struct State {
+    scope: Rc<RefCell<Scope>>,
+    session: Rc<RefCell<Session>>,
+    // variables bound by the signature of the running definition
+    bindings: Substitution,
 }
 
 impl State {
     pub fn new() -> Self {
         Self {
-            parent: None,
-            definitions: ArrayMap::new(),
-            return_value: None,
-            variables: HashMap::new(),
-            wildcards: HashMap::new(),
+            scope: Rc::new(RefCell::new(Scope::default())),
+            session: Rc::new(RefCell::new(Session::default())),
+            bindings: Substitution::new(),
         }
     }
 
-    pub fn with_parent(&self, parent: *mut Self) -> Self {
-        let mut res = self.clone();
-        res.parent = Some(parent);
-        res
+    // new empty scope nested in this one
+    pub fn child(&self) -> Self {
+        let scope = Scope {
+            parent: Some(Rc::clone(&self.scope)),
+            ..Scope::default()
+        };
+
+        Self {
+            scope: Rc::new(RefCell::new(scope)),
+            session: Rc::clone(&self.session),
+            bindings: Substitution::new(),
+        }
     }
 
-    pub fn find_all(&self, token: Token) -> Vec<Definition> {
-        // find local matches first if any
-        let found = self.definitions.search(token.clone());
+    // handle on the same scope with `bindings` for the running definition
+    pub fn with_bindings(&self, bindings: Substitution) -> Self {
+        Self {
+            scope: Rc::clone(&self.scope),
+            session: Rc::clone(&self.session),
+            bindings,
+        }
+    }
+
+    pub fn bindings(&self) -> &Substitution {
+        &self.bindings
+    }
+
+    pub fn parent(&self) -> Option<Self> {
+        let scope = self.scope.borrow();
+
+        scope.parent.as_ref().map(|parent| Self {
+            scope: Rc::clone(parent),
+            session: Rc::clone(&self.session),
+            bindings: Substitution::new(),
+        })
+    }
+
+    // variables bound in this scope only, enclosing scopes already had
+    // theirs substituted into the code this scope runs
+    pub fn variables(&self) -> HashMap<String, Token> {
+        self.scope.borrow().variables.clone()
+    }
 
-        if found.len() > 0 {
-            return found;
+    pub fn set_variable(&mut self, name: &str, value: Token) {
+        self.scope
+            .borrow_mut()
+            .variables
+            .insert(name.to_owned(), value);
+    }
+
+    pub fn datalog(&self) -> bool {
+        self.session.borrow().datalog
+    }
+
+    pub fn set_datalog(&mut self, enabled: bool) {
+        self.session.borrow_mut().datalog = enabled;
+    }
+
+    pub fn allows(&self, capability: &str) -> bool {
+        !self.session.borrow().denied.contains(capability)
+    }
+
+    pub fn set_capability(&mut self, capability: &str, enabled: bool) {
+        let denied = &mut self.session.borrow_mut().denied;
+        match enabled {
+            true => denied.remove(capability),
+            false => denied.insert(capability.to_owned()),
+        };
+    }
+
+    pub fn args(&self) -> Vec<String> {
+        self.session.borrow().args.clone()
+    }
+
+    pub fn set_args(&mut self, args: Vec<String>) {
+        self.session.borrow_mut().args = args;
+    }
+
+    pub fn tables(&self) -> RefMut<'_, Tables> {
+        RefMut::map(self.session.borrow_mut(), |session| &mut session.tables)
+    }
+
+    // every `@dec` fact and `@def` rule visible from this scope, outermost
+    // scope first and in the order they were added
+    pub fn clauses(&self) -> Vec<Definition> {
+        let mut scopes = vec![];
+        let mut state = Some(self.clone());
+        while let Some(current) = state {
+            state = current.parent();
+            scopes.push(current);
         }
 
-        if let Some(parent) = self.parent {
-            let found = unsafe { (*parent).find_all(token) };
-            return found;
+        scopes
+            .iter()
+            .rev()
+            .flat_map(|state| state.scope.borrow().definitions.iter().collect::<Vec<_>>())
+            .map(|(_, def)| def)
+            .filter(Definition::is_clause)
+            .collect()
+    }
+
+    // identity of the nearest scope holding definitions, the clauses
+    // visible from this state are the ones of that scope and its parents
+    pub fn scope_id(&self) -> usize {
+        let mut scope = Rc::clone(&self.scope);
+        loop {
+            let parent = match scope.borrow() {
+                current if current.definitions.is_empty() => current.parent.clone(),
+                _ => None,
+            };
+
+            match parent {
+                Some(parent) => scope = parent,
+                None => return Rc::as_ptr(&scope) as usize,
+            }
+        }
+    }
+
+    pub fn find_all(&self, token: Token) -> Vec<Definition> {
+        let mut state = Some(self.clone());
+
+        while let Some(current) = state {
+            // find local matches first if any, a scope only hides its parents
+            // for calls one of its definitions unifies with
+            let found = current.scope.borrow().definitions.search(&token);
+            if found.iter().any(|def| def.matches(&token).is_some()) {
+                return found;
+            }
+
+            state = current.parent();
         }
 
         vec![]
     }
 
-    pub fn run(&mut self, token: Token) -> Token {
+    // the expected number of arguments of any definition called with `head`
+    fn find_arity(&self, head: &Token) -> Option<usize> {
+        let mut state = Some(self.clone());
+
+        while let Some(current) = state {
+            for (sig, _) in current.scope.borrow().definitions.iter() {
+                if let Token::List(list) = &sig {
+                    match (list.first(), head) {
+                        (Some(Token::Identifier(a)), Token::Identifier(b))
+                        | (Some(Token::Keyword(a)), Token::Keyword(b))
+                        | (Some(Token::Operator(a)), Token::Operator(b))
+                            if a == b =>
+                        {
+                            return Some(list.len() - 1)
+                        }
+                        _ => (),
+                    }
+                }
+            }
+
+            state = current.parent();
+        }
+
+        None
+    }
+    // lists starting with an identifier, keyword or operator are calls,
+    // anything else without a matching definition is plain data
+    fn unresolved(&self, token: Token) -> Result<Token, LexError> {
+        let (head, found) = match &token {
+            Token::List(list) => match list.first() {
+                Some(head) => (head.clone(), list.len() - 1),
+                None => return Ok(token),
+            },
+            _ => return Ok(token),
+        };
+
+        match head {
+            Token::Identifier(_) | Token::Keyword(_) | Token::Operator(_) => {
+                match self.find_arity(&head) {
+                    Some(expected) => Err(LexError::Arity {
+                        expected,
+                        found,
+                        call: token,
+                    }),
+                    None => Err(LexError::Unresolved(token)),
+                }
+            }
+            _ => Ok(token),
+        }
+    }
+
+    pub fn run(&mut self, token: Token) -> Result<Token, LexError> {
         let mut res: Vec<Token> = vec![];
 
+        // the index only checks `Token::eq`, keep the definitions the call
+        // actually unifies with
         let defs = self.find_all(token.clone());
         for def in defs {
-            let result = def.handle(&mut self.clone(), token.clone());
-            res.push(result);
+            if let Some(bindings) = def.matches(&token) {
+                let result = def.call(&mut self.clone(), token.clone(), bindings)?;
+                res.push(result);
+            }
         }
 
-        if res.len() == 0 {
-            return token;
+        if res.is_empty() {
+            return self.unresolved(token);
         } else if res.len() == 1 {
-            return res[0].clone();
+            return Ok(res[0].clone());
         }
 
-        Token::List(res)
+        Ok(Token::List(res))
     }
 
-    pub fn exec(&mut self, token: Token) -> Token {
+    // call `func`, a closure or the name of a definition, with arguments
+    // that are already evaluated
+    pub fn apply(&mut self, func: Token, args: Vec<Token>) -> Result<Token, LexError> {
+        match func.inner() {
+            Token::Closure(closure) => {
+                let mut call = vec![func.clone()];
+                call.extend(args.clone());
+
+                closure.apply(&Token::List(call), args)
+            }
+            Token::Identifier(_) | Token::Operator(_) | Token::Keyword(_) => {
+                let mut call = vec![func];
+                call.extend(args);
+
+                self.run(Token::List(call))
+            }
+            _ => Err(LexError::TypeMismatch {
+                expected: "closure or definition name".to_owned(),
+                found: func,
+            }),
+        }
+    }
+
+    pub fn exec(&mut self, token: Token) -> Result<Token, LexError> {
+        match token {
+            Token::Spanned(span, token) => self.eval(*token, true).map_err(|err| err.at(span)),
+            token => self.eval(token, false),
+        }
+    }
+
+    // `source` tells tokens from the parsed program apart from values
+    // handlers return, which are only evaluated again as data
+    fn eval(&mut self, token: Token, source: bool) -> Result<Token, LexError> {
+        if let Token::Spanned(_, _) = token {
+            return self.exec(token);
+        }
+
+        // the frame is popped on every way out, errors included, so the
+        // depth of the trace stays right
         self.push_trace(token.clone());
-        let mut state = Self::new().with_parent(self);
+        let res = self.step(token, source);
+        self.pop_trace(&res);
+
+        res
+    }
+
+    fn step(&mut self, token: Token, source: bool) -> Result<Token, LexError> {
+        let mut state = self.child();
 
         let mut token = token;
         match token {
-            Token::Document(_, content) => token = state.exec(*content.clone()),
+            // evaluate the content like a line so top level definitions are
+            // added to this scope and outlive the document
+            Token::Document(_, content) => token = self.exec(*content)?,
+            Token::List(list) if special_form(&list) => token = Token::List(list),
             Token::List(list) => {
                 let mut res = vec![];
 
                 for item in list {
-                    let out = state.exec(item);
-                    if state.return_value != None {
-                        return state.return_value.unwrap();
+                    // lists inside a value were evaluated when it was made,
+                    // running them again would run the data they hold
+                    if !source && matches!(item, Token::List(_)) {
+                        res.push(item);
+                        continue;
+                    }
+
+                    let out = state.exec(item)?;
+                    if let Some(value) = state.return_value() {
+                        return Ok(value);
                     }
                     res.push(out);
                 }
@@ -90,10 +354,36 @@ impl State {
                 token = Token::List(res)
             }
             Token::Definition(_, _) => (),
-            token => return token,
+            // keyword arguments of values, e.g. query answers, are data
+            Token::KeywordArg(name, value) if source => {
+                return Ok(Token::KeywordArg(name, Box::new(self.exec(*value)?)))
+            }
+            // named constants, e.g. `.pi` of the math module
+            Token::Identifier(_) if source => {
+                return match self.find_all(token.clone()).first() {
+                    Some(def) => def.handle(&mut state, token),
+                    None => Ok(token),
+                }
+            }
+            // substituted by `@let`
+            Token::Variable(name, Some(value)) => {
+                return Ok(value
+                    .values()
+                    .first()
+                    .cloned()
+                    .unwrap_or(Token::Variable(name, None)))
+            }
+            token => return Ok(token),
         };
 
-        let res = self.run(token.clone());
+        let res = match &token {
+            // a list from source headed by a closure applies it to the rest
+            Token::List(list) if source => match list.first() {
+                Some(Token::Closure(closure)) => closure.apply(&token, list[1..].to_vec())?,
+                _ => self.run(token)?,
+            },
+            _ => self.run(token)?,
+        };
         // let found = state.find(token.clone());
 
         // let res = match found {
@@ -101,13 +391,14 @@ impl State {
         //     None => token,
         // };
 
-        self.pop_trace(res.clone());
-
-        res
+        Ok(res)
     }
 
+    // memoized answers may miss what the definition adds, so they are
+    // forgotten
     pub fn add(&mut self, token: Token, definition: Definition) {
-        self.definitions.push(token, definition)
+        self.scope.borrow_mut().definitions.push(token, definition);
+        self.tables().clear();
     }
 
     pub fn load<T: Module>(&mut self, module: T) {
@@ -118,64 +409,55 @@ impl State {
     }
 
     pub fn include<T: Module>(&mut self, module: T) {
-        if let Some(parent) = self.parent {
-            let defs = module.load();
-            for def in defs {
-                unsafe { (*parent).add(def.inp_sig.clone(), def) };
-            }
+        if let Some(mut parent) = self.parent() {
+            parent.load(module);
         }
     }
 
     pub fn add_to_parent(&mut self, token: Token, definition: Definition) {
-        if let Some(parent) = self.parent {
-            unsafe { (*parent).add(token, definition) };
+        if let Some(mut parent) = self.parent() {
+            parent.add(token, definition);
         }
     }
 
     pub fn _return(&mut self, value: Token) {
-        if let Some(parent) = self.parent {
-            unsafe { (*parent).return_value = Some(value) };
+        if let Some(parent) = self.parent() {
+            parent.scope.borrow_mut().return_value = Some(value);
         }
     }
 
     fn push_trace(&mut self, token: Token) {
-        unsafe {
-            match token {
-                Token::List(_) => self.print_trace("in", token),
-                Token::Document(_, _) => self.print_trace("in", token),
-                Token::Definition(_, _) => self.print_trace("in", token),
-                _ => (),
-            }
-
-            DEPTH += 1;
+        match token {
+            Token::List(_) => self.print_trace("in", token),
+            Token::Document(_, _) => self.print_trace("in", token),
+            Token::Definition(_, _) => self.print_trace("in", token),
+            _ => (),
         }
+
+        DEPTH.fetch_add(1, Ordering::Relaxed);
     }
 
-    fn pop_trace(&mut self, token: Token) {
-        unsafe {
-            DEPTH -= 1;
+    fn pop_trace(&mut self, res: &Result<Token, LexError>) {
+        DEPTH.fetch_sub(1, Ordering::Relaxed);
 
-            match token {
-                Token::List(_) => self.print_trace("out", token),
-                Token::Document(_, _) => self.print_trace("out", token),
-                Token::Definition(_, _) => self.print_trace("out", token),
-                _ => (),
-            }
+        match res {
+            Ok(token @ Token::List(_)) => self.print_trace("out", token.clone()),
+            Ok(token @ Token::Document(_, _)) => self.print_trace("out", token.clone()),
+            Ok(token @ Token::Definition(_, _)) => self.print_trace("out", token.clone()),
+            _ => (),
         }
     }
 
     fn print_trace(&self, prefix: &str, token: Token) {
-        unsafe {
-            if DEBUG {
-                for _ in 1..DEPTH {
-                    print!("\t");
-                }
-                println!("{} {}", prefix, token);
+        if DEBUG.load(Ordering::Relaxed) {
+            for _ in 1..DEPTH.load(Ordering::Relaxed) {
+                print!("\t");
             }
+            println!("{} {}", prefix, token);
         }
     }
 
     pub fn return_value(&self) -> Option<Token> {
-        self.return_value.clone()
+        self.scope.borrow().return_value.clone()
     }
 }
@@ -0,0 +1,117 @@
+#[cfg(test)]
+use super::{
+    definition::Definition,
+    error::LexError,
+    evaluator::Engine,
+    grammar::{repl_line, token::Token},
+    state::State,
+};
+#[cfg(test)]
+use std::rc::Rc;
+
+#[cfg(test)]
+fn constant(name: &str, value: Token) -> Definition {
+    let sig = Token::List(vec![Token::Identifier(name.to_owned())]);
+    let out = value.clone();
+
+    Definition {
+        inp_sig: sig,
+        out_sig: Token::Value,
+        res_sig: Token::Value,
+        func: Rc::new(Box::new(move |_: &mut State, _: Token| Ok(out.clone()))),
+    }
+}
+
+#[cfg(test)]
+fn call(name: &str) -> Token {
+    Token::List(vec![Token::Identifier(name.to_owned())])
+}
+
+#[test]
+fn should_find_definitions_of_enclosing_scopes() {
+    let mut root = State::new();
+    root.add(call("answer"), constant("answer", Token::Integer(42)));
+
+    let mut child = root.child().child();
+
+    assert_eq!(Ok(Token::Integer(42)), child.exec(call("answer")))
+}
+
+#[test]
+fn should_not_leak_definitions_to_enclosing_scopes() {
+    let mut root = State::new();
+    let mut child = root.child();
+    child.add(call("answer"), constant("answer", Token::Integer(42)));
+
+    assert_eq!(
+        Err(LexError::Unresolved(call("answer"))),
+        root.exec(call("answer"))
+    )
+}
+
+#[test]
+fn should_prefer_innermost_definition() {
+    let mut root = State::new();
+    root.add(call("answer"), constant("answer", Token::Integer(1)));
+    let mut child = root.child();
+    child.add(call("answer"), constant("answer", Token::Integer(2)));
+
+    assert_eq!(Ok(Token::Integer(2)), child.exec(call("answer")))
+}
+
+#[test]
+fn should_fall_back_to_enclosing_definition_that_matches() {
+    let mut engine = Engine::new();
+    engine.parse_line("(@def (.same X Y) false)").unwrap();
+
+    let res = engine
+        .parse_line("((@fn (A) (@do (@def (.same X X) true) (.same A 2))) 1)")
+        .map(Token::unspanned);
+    assert_eq!(Ok(Token::_false()), res);
+
+    let res = engine
+        .parse_line("((@fn (A) (@do (@def (.same X X) true) (.same A 1))) 1)")
+        .map(Token::unspanned);
+    assert_eq!(Ok(Token::_true()), res);
+}
+
+#[test]
+fn should_keep_enclosing_scope_alive() {
+    let mut child = {
+        let mut root = State::new();
+        root.add(call("answer"), constant("answer", Token::Integer(42)));
+        root.child()
+    };
+
+    assert_eq!(Ok(Token::Integer(42)), child.exec(call("answer")))
+}
+
+#[test]
+fn should_keep_definitions_between_lines() {
+    let mut engine = Engine::new();
+
+    engine.parse_line("(@def (.answer) 42)").unwrap();
+
+    assert_eq!(Ok(Token::Integer(42)), engine.parse_line("(.answer)"))
+}
+
+#[test]
+fn should_evaluate_operands_of_functions_named_like_keywords() {
+    let engine = Engine::new();
+    let sig = Token::List(vec![Token::Identifier("if".to_owned()), Token::Value]);
+    let def = Definition {
+        inp_sig: sig.clone(),
+        out_sig: Token::Value,
+        res_sig: Token::Value,
+        func: Rc::new(Box::new(|_: &mut State, arg: Token| match arg.inner() {
+            Token::List(list) => Ok(list[1].clone()),
+            _ => Ok(arg),
+        })),
+    };
+    let mut state = engine.state().clone();
+    state.add(sig, def);
+
+    let call = repl_line("(.if (+ 1 2))").unwrap();
+
+    assert_eq!(Ok(Token::Integer(3)), state.exec(call))
+}
@@ -1,47 +1,79 @@
 use super::Core;
 
+use crate::error::LexError;
 use crate::grammar::token::Token;
 use crate::state::State;
 use crate::utils::operation::BinaryOperation;
 
-impl Core {
-    pub fn multiply(state: &mut State, arg: Token) -> Token {
-        let mut op = BinaryOperation::new();
-        op.for_number(Box::new(|_, (a, b)| Some(Token::Number(a * b))));
+// integer arithmetic that falls back to floats when the result does not fit
+fn checked(integer: fn(i64, i64) -> Option<i64>, number: fn(f64, f64) -> f64) -> BinaryOperation {
+    let mut op = BinaryOperation::new();
+    op.for_integer(Box::new(move |_, (a, b)| match integer(a, b) {
+        Some(n) => Ok(Token::Integer(n)),
+        None => Ok(Token::Number(number(a as f64, b as f64))),
+    }));
+    op.for_number(Box::new(move |_, (a, b)| Ok(Token::Number(number(a, b)))));
 
-        match op.exec(state, arg) {
-            Some(val) => val,
-            _ => Token::_false(),
-        }
+    op
+}
+
+impl Core {
+    pub fn multiply(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        checked(i64::checked_mul, |a, b| a * b).exec(state, arg)
     }
 
-    pub fn div(state: &mut State, arg: Token) -> Token {
+    // integers that divide exactly stay integers, any other quotient is a
+    // float
+    pub fn div(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let call = arg.clone();
         let mut op = BinaryOperation::new();
-        op.for_number(Box::new(|_, (a, b)| Some(Token::Number(a / b))));
+        op.for_integer(Box::new(move |_, (a, b)| {
+            if b == 0 {
+                return Err(LexError::DivisionByZero(call.clone()));
+            }
 
-        match op.exec(state, arg) {
-            Some(val) => val,
-            _ => Token::_false(),
-        }
+            match a.checked_rem(b) {
+                Some(0) => Ok(Token::Integer(a / b)),
+                _ => Ok(Token::Number(a as f64 / b as f64)),
+            }
+        }));
+        op.for_number(Box::new(|_, (a, b)| Ok(Token::Number(a / b))));
+
+        op.exec(state, arg)
     }
 
-    pub fn min(state: &mut State, arg: Token) -> Token {
+    // `(@div a b)` divides and drops the fractional part, rounding toward
+    // zero
+    pub fn int_div(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let call = arg.clone();
         let mut op = BinaryOperation::new();
-        op.for_number(Box::new(|_, (a, b)| Some(Token::Number(a - b))));
+        let zero = call.clone();
+        op.for_integer(Box::new(move |_, (a, b)| {
+            if b == 0 {
+                return Err(LexError::DivisionByZero(zero.clone()));
+            }
+
+            match a.checked_div(b) {
+                Some(n) => Ok(Token::Integer(n)),
+                None => Ok(Token::Number((a as f64 / b as f64).trunc())),
+            }
+        }));
+        op.for_number(Box::new(move |_, (a, b)| {
+            if b == 0.0 {
+                return Err(LexError::DivisionByZero(call.clone()));
+            }
+
+            Ok(Token::Number((a / b).trunc()))
+        }));
 
-        match op.exec(state, arg) {
-            Some(val) => val,
-            _ => Token::_false(),
-        }
+        op.exec(state, arg)
     }
 
-    pub fn add(state: &mut State, arg: Token) -> Token {
-        let mut op = BinaryOperation::new();
-        op.for_number(Box::new(|_, (a, b)| Some(Token::Number(a + b))));
+    pub fn min(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        checked(i64::checked_sub, |a, b| a - b).exec(state, arg)
+    }
 
-        match op.exec(state, arg) {
-            Some(val) => val,
-            _ => Token::_false(),
-        }
+    pub fn add(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        checked(i64::checked_add, |a, b| a + b).exec(state, arg)
     }
 }
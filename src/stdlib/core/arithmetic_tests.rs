@@ -0,0 +1,59 @@
+#[cfg(test)]
+use crate::{error::LexError, evaluator::Engine, grammar::token::Token};
+
+#[cfg(test)]
+fn eval(line: &str) -> Result<Token, LexError> {
+    Engine::new()
+        .parse_line(line)
+        .map(Token::unspanned)
+        .map_err(LexError::unspanned)
+}
+
+#[test]
+fn should_keep_integers_exact() {
+    assert_eq!(Ok(Token::Integer(5)), eval("(+ 2 3)"));
+    assert_eq!(Ok(Token::Integer(-1)), eval("(- 2 3)"));
+    assert_eq!(Ok(Token::Integer(6)), eval("(* 2 3)"));
+    assert_eq!(Ok(Token::Integer(3)), eval("(/ 6 2)"));
+}
+
+#[test]
+fn should_multiply_with_star() {
+    // `*` used to be bound to division
+    assert_eq!(Ok(Token::Integer(12)), eval("(* 6 2)"));
+    assert_eq!(Ok(Token::Number(1.0)), eval("(* 0.5 2.0)"));
+}
+
+#[test]
+fn should_promote_to_float() {
+    assert_eq!(Ok(Token::Number(3.5)), eval("(/ 7 2)"));
+    assert_eq!(Ok(Token::Number(3.5)), eval("(+ 1 2.5)"));
+    assert_eq!(Ok(Token::Number(5.0)), eval("(* 2.5 2)"));
+}
+
+#[test]
+fn should_promote_to_float_on_overflow() {
+    let res = eval("(+ 0x7fff_ffff_ffff_ffff 1)");
+
+    assert_eq!(Ok(Token::Number(9223372036854775808.0)), res)
+}
+
+#[test]
+fn should_compare_integers_and_floats() {
+    assert_eq!(Ok(Token::_true()), eval("(< 1 1.5)"));
+    assert_eq!(Ok(Token::_true()), eval("(= 2 2.0)"));
+}
+
+#[test]
+fn should_print_floats_apart_from_integers() {
+    assert_eq!("3", Token::Integer(3).to_string());
+    assert_eq!("3.0", Token::Number(3.0).to_string());
+    assert_eq!("3.5", Token::Number(3.5).to_string());
+}
+
+#[test]
+fn error_on_division_by_zero() {
+    let res = eval("(/ 1 0)");
+
+    assert!(matches!(res, Err(LexError::DivisionByZero(_))))
+}
@@ -0,0 +1,53 @@
+use super::Core;
+
+use crate::error::LexError;
+use crate::grammar::token::Token;
+use crate::state::State;
+use crate::utils::variables::inject_variables;
+
+// name and value of a `(Variable value)` binding
+fn binding(token: &Token) -> Result<(String, Token), LexError> {
+    if let Token::List(list) = token.inner() {
+        if let [name, value] = &list[..] {
+            if let Token::Variable(name, _) = name.inner() {
+                return Ok((name.clone(), value.clone()));
+            }
+        }
+    }
+
+    Err(LexError::TypeMismatch {
+        expected: "(Variable value)".to_owned(),
+        found: token.clone(),
+    })
+}
+
+impl Core {
+    // `(@let ((X 1) (Y (+ X 1))) body)` evaluates the values in order, each
+    // one seeing the variables bound before it, and substitutes them into
+    // the body, a variable bound again shadows the previous binding
+    pub fn let_in(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let (bindings, body) = match arg.inner() {
+            Token::List(list) => (list[1].clone(), list[2].clone()),
+            _ => return Ok(arg),
+        };
+
+        let bindings = match bindings.inner() {
+            Token::List(list) => list.clone(),
+            _ => {
+                return Err(LexError::TypeMismatch {
+                    expected: "list of bindings".to_owned(),
+                    found: bindings,
+                })
+            }
+        };
+
+        let mut scope = state.child();
+        for token in bindings.iter() {
+            let (name, value) = binding(token)?;
+            let value = scope.exec(inject_variables(value, scope.variables()))?;
+            scope.set_variable(&name, value);
+        }
+
+        scope.exec(inject_variables(body, scope.variables()))
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+use crate::{error::LexError, evaluator::Engine, grammar::token::Token};
+
+#[cfg(test)]
+fn eval(line: &str) -> Result<Token, LexError> {
+    Engine::new()
+        .parse_line(line)
+        .map(Token::unspanned)
+        .map_err(LexError::unspanned)
+}
+
+#[test]
+fn should_bind_variables_in_body() {
+    assert_eq!(Ok(Token::Integer(3)), eval("(@let ((X 1) (Y 2)) (+ X Y))"));
+}
+
+#[test]
+fn should_bind_in_order() {
+    assert_eq!(Ok(Token::Integer(2)), eval("(@let ((X 1) (Y (+ X 1))) Y)"));
+}
+
+#[test]
+fn should_shadow_outer_bindings() {
+    assert_eq!(
+        Ok(Token::Integer(22)),
+        eval("(@let ((X 1)) (@let ((X 10) (Y (+ X 1))) (+ X (+ Y 1))))")
+    );
+    assert_eq!(Ok(Token::Integer(3)), eval("(@let ((X 1) (X (+ X 2))) X)"));
+}
+
+#[test]
+fn should_not_leak_bindings_out_of_body() {
+    let mut engine = Engine::new();
+    engine.parse_line("(@let ((X 1)) X)").unwrap();
+
+    let res = engine.parse_line("X").map(Token::unspanned);
+
+    assert_eq!(Ok(Token::Variable("X".to_owned(), None)), res)
+}
+
+#[test]
+fn should_scope_lexically() {
+    let mut engine = Engine::new();
+    // `X` in the body of `.get` is not the one bound where it is called
+    engine.parse_line("(@def (.get) X)").unwrap();
+
+    let res = engine
+        .parse_line("(@let ((X 1)) (.get))")
+        .map(Token::unspanned);
+
+    assert_eq!(Ok(Token::Variable("X".to_owned(), None)), res)
+}
+
+#[test]
+fn should_capture_bindings_in_definitions() {
+    let mut engine = Engine::new();
+    engine
+        .parse_line("(@let ((Step 5)) (@def (.next N) (+ N Step)))")
+        .unwrap();
+
+    let res = engine.parse_line("(.next 1)").map(Token::unspanned);
+
+    assert_eq!(Ok(Token::Integer(6)), res)
+}
+
+#[test]
+fn should_see_outer_bindings_in_nested_let() {
+    assert_eq!(
+        Ok(Token::Integer(5)),
+        eval("(@let ((X 5)) (@let ((Z 1)) X))")
+    );
+    assert_eq!(
+        Ok(Token::Integer(6)),
+        eval("(@let ((X 5)) (@let ((Z 1)) (+ X Z)))")
+    );
+}
+
+#[test]
+fn should_leave_names_rebound_by_inner_binders() {
+    assert_eq!(
+        Ok(Token::Integer(15)),
+        eval("(@let ((X 1)) ((@fn (X) (+ X 10)) 5))")
+    );
+    assert_eq!(
+        Ok(Token::Integer(7)),
+        eval("(@let ((X 1)) (@match (7) ((X) X)))")
+    );
+}
+
+#[test]
+fn should_leave_names_rebound_by_inner_definitions() {
+    let mut engine = Engine::new();
+    engine
+        .parse_line("(@let ((X 1)) (@def (.same X) X))")
+        .unwrap();
+
+    let res = engine.parse_line("(.same 4)").map(Token::unspanned);
+
+    assert_eq!(Ok(Token::Integer(4)), res)
+}
+
+#[test]
+fn error_on_malformed_binding() {
+    let res = eval("(@let ((1 X)) X)");
+
+    assert!(matches!(
+        res,
+        Err(LexError::TypeMismatch { expected, .. }) if expected == "(Variable value)"
+    ))
+}
@@ -0,0 +1,79 @@
+use super::Core;
+
+use crate::error::LexError;
+use crate::grammar::token::Token;
+use crate::state::State;
+use crate::utils::compare;
+use crate::utils::operation::BinaryOperation;
+use std::cmp::Ordering;
+
+// ordering comparison of numbers, strings, atoms, booleans and lists
+fn comparison(test: fn(Ordering) -> bool) -> BinaryOperation {
+    let mut op = BinaryOperation::new();
+    op.for_integer(Box::new(move |_, (a, b)| {
+        Ok(Token::Boolean(test(a.cmp(&b))))
+    }))
+    .for_number(Box::new(move |_, (a, b)| {
+        Ok(Token::Boolean(a.partial_cmp(&b).is_some_and(test)))
+    }))
+    .for_string(Box::new(move |_, (a, b)| {
+        Ok(Token::Boolean(test(a.cmp(&b))))
+    }))
+    .for_atom(Box::new(move |_, (a, b)| {
+        Ok(Token::Boolean(test(a.cmp(&b))))
+    }))
+    .for_boolean(Box::new(move |_, (a, b)| {
+        Ok(Token::Boolean(test(a.cmp(&b))))
+    }))
+    .for_list(Box::new(move |_, (a, b)| {
+        let (a, b) = (Token::List(a), Token::List(b));
+        match compare::compare(&a, &b) {
+            Some(ordering) => Ok(Token::Boolean(test(ordering))),
+            None => Err(LexError::TypeMismatch {
+                expected: "lists of comparable values".to_owned(),
+                found: Token::List(vec![a, b]),
+            }),
+        }
+    }));
+
+    op
+}
+
+// structural equality of any two values
+fn equality(expected: bool) -> BinaryOperation {
+    let mut op = BinaryOperation::new();
+    op.for_any(Box::new(move |state, (a, b)| {
+        let a = state.exec(a)?;
+        let b = state.exec(b)?;
+
+        Ok(Token::Boolean(compare::equal(&a, &b) == expected))
+    }));
+
+    op
+}
+
+impl Core {
+    pub fn eq(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        equality(true).exec(state, arg)
+    }
+
+    pub fn ne(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        equality(false).exec(state, arg)
+    }
+
+    pub fn lt(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        comparison(Ordering::is_lt).exec(state, arg)
+    }
+
+    pub fn gt(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        comparison(Ordering::is_gt).exec(state, arg)
+    }
+
+    pub fn le(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        comparison(Ordering::is_le).exec(state, arg)
+    }
+
+    pub fn ge(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        comparison(Ordering::is_ge).exec(state, arg)
+    }
+}
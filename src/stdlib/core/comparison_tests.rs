@@ -0,0 +1,92 @@
+#[cfg(test)]
+use crate::{error::LexError, evaluator::Engine, grammar::token::Token};
+
+#[cfg(test)]
+fn eval(line: &str) -> Result<Token, LexError> {
+    Engine::new()
+        .parse_line(line)
+        .map(Token::unspanned)
+        .map_err(LexError::unspanned)
+}
+
+#[test]
+fn should_compare_numbers() {
+    assert_eq!(Ok(Token::_true()), eval("(< 1 2)"));
+    assert_eq!(Ok(Token::_false()), eval("(> 1 2)"));
+    assert_eq!(Ok(Token::_true()), eval("(<= 2 2)"));
+    assert_eq!(Ok(Token::_true()), eval("(>= (+ 1 2) 3)"));
+}
+
+#[test]
+fn should_compare_strings_atoms_and_booleans() {
+    assert_eq!(Ok(Token::_true()), eval("(< \"abc\" \"abd\")"));
+    assert_eq!(Ok(Token::_true()), eval("(> beta alpha)"));
+    assert_eq!(Ok(Token::_true()), eval("(< false true)"));
+}
+
+#[test]
+fn should_compare_lists_structurally() {
+    assert_eq!(Ok(Token::_true()), eval("(< (1 2) (1 3))"));
+    assert_eq!(Ok(Token::_true()), eval("(= (1 (a b)) (1 (a b)))"));
+    assert_eq!(Ok(Token::_true()), eval("(!= (1 2) (1 2 3))"));
+}
+
+#[test]
+fn should_not_equal_values_of_different_kinds() {
+    assert_eq!(Ok(Token::_false()), eval("(= 1 \"1\")"));
+    assert_eq!(Ok(Token::_true()), eval("(!= a \"a\")"));
+}
+
+#[test]
+fn error_on_ordering_different_kinds() {
+    let res = eval("(< 1 \"one\")");
+
+    assert_eq!(
+        Err(LexError::TypeMismatch {
+            expected: "number or boolean or atom or string or list".to_owned(),
+            found: Token::List(vec![Token::Integer(1), Token::String("one".to_owned())]),
+        }),
+        res
+    )
+}
+
+#[test]
+fn should_unify_with_equals_in_queries() {
+    let mut engine = Engine::new();
+    engine
+        .parse("@document ids (@dec (10 .name alice)) (@dec (11 .name bob))")
+        .unwrap();
+
+    let res = engine.parse_line("(.select (ID name) where: (= ID 10))");
+    assert_eq!(
+        Ok(Token::List(vec![Token::List(vec![
+            Token::Integer(10),
+            Token::Atom("name".to_owned()),
+        ])])),
+        res
+    );
+
+    let res = engine.parse_line("(.select Name from: (ID .name Name) where: (> ID 10))");
+    assert_eq!(
+        Ok(Token::List(vec![Token::List(vec![Token::Atom(
+            "bob".to_owned()
+        )])])),
+        res
+    );
+}
+
+#[test]
+fn should_unify_integers_with_equal_floats_in_queries() {
+    let mut engine = Engine::new();
+    engine
+        .parse("@document ids (@dec (10 .name alice)) (@dec (11 .name bob))")
+        .unwrap();
+
+    let res = engine.parse_line("(.select Name from: (ID .name Name) where: (= ID 10.0))");
+    assert_eq!(
+        Ok(Token::List(vec![Token::List(vec![Token::Atom(
+            "alice".to_owned()
+        )])])),
+        res.map(Token::unspanned)
+    );
+}
@@ -0,0 +1,62 @@
+use super::Core;
+
+use crate::error::LexError;
+use crate::grammar::token::Token;
+use crate::state::State;
+use crate::stdlib::{holds, raw_operands};
+use crate::utils::unify;
+
+// `(left right)` arm of a `@cond` or `@match`
+fn arm(token: &Token, expected: &str) -> Result<(Token, Token), LexError> {
+    match token.inner() {
+        Token::List(list) if list.len() == 2 => Ok((list[0].clone(), list[1].clone())),
+        _ => Err(LexError::TypeMismatch {
+            expected: expected.to_owned(),
+            found: token.clone(),
+        }),
+    }
+}
+
+impl Core {
+    // `(@if test then else)` evaluates `then` unless the test is false, in
+    // which case it is `else`, or false without one
+    pub fn if_then(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let operands = raw_operands(&arg);
+        let test = state.exec(operands[0].clone())?;
+
+        match (holds(&test), operands.get(2)) {
+            (true, _) => state.exec(operands[1].clone()),
+            (false, Some(otherwise)) => state.exec(otherwise.clone()),
+            (false, None) => Ok(Token::_false()),
+        }
+    }
+
+    // `(@cond (test body) ...)` evaluates the body of the first arm whose test
+    // is not false, false if there is none
+    pub fn cond(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        for operand in raw_operands(&arg) {
+            let (test, body) = arm(&operand, "(test body)")?;
+            if holds(&state.exec(test)?) {
+                return state.exec(body);
+            }
+        }
+
+        Ok(Token::_false())
+    }
+
+    // `(@match value (pattern body) ...)` evaluates the body of the first arm
+    // whose pattern matches the value, with the variables the pattern binds
+    pub fn match_value(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut operands = raw_operands(&arg);
+        let value = state.exec(operands.remove(0))?;
+
+        for operand in operands {
+            let (pattern, body) = arm(&operand, "(pattern body)")?;
+            if let Some(bindings) = unify::matches(&pattern, &value) {
+                return state.exec(bindings.resolve(body));
+            }
+        }
+
+        Err(LexError::NoMatch(value.unspanned()))
+    }
+}
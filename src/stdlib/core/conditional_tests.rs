@@ -0,0 +1,99 @@
+#[cfg(test)]
+use crate::{error::LexError, evaluator::Engine, grammar::token::Token};
+
+#[cfg(test)]
+fn eval(line: &str) -> Result<Token, LexError> {
+    Engine::new()
+        .parse_line(line)
+        .map(Token::unspanned)
+        .map_err(LexError::unspanned)
+}
+
+#[test]
+fn should_branch_on_if() {
+    assert_eq!(Ok(Token::Integer(1)), eval("(@if (< 1 2) 1 2)"));
+    assert_eq!(Ok(Token::Integer(2)), eval("(@if (> 1 2) 1 2)"));
+    assert_eq!(Ok(Token::_false()), eval("(@if false 1)"));
+}
+
+#[test]
+fn should_only_evaluate_taken_branch() {
+    assert_eq!(Ok(Token::Integer(1)), eval("(@if true 1 (.undefined))"));
+}
+
+#[test]
+fn should_take_first_holding_arm_of_cond() {
+    let res = eval("(@cond ((> 1 2) a) ((< 1 2) b) (true c))");
+
+    assert_eq!(Ok(Token::Atom("b".to_owned())), res)
+}
+
+#[test]
+fn should_be_false_when_no_arm_of_cond_holds() {
+    assert_eq!(Ok(Token::_false()), eval("(@cond (false a))"));
+}
+
+#[test]
+fn should_destructure_on_match() {
+    let res = eval("(@match (point 1 2) ((circle R) R) ((point X Y) (+ X Y)))");
+
+    assert_eq!(Ok(Token::Integer(3)), res)
+}
+
+#[test]
+fn should_match_wildcards_and_values() {
+    assert_eq!(
+        Ok(Token::Atom("other".to_owned())),
+        eval("(@match (+ 1 1) (1 one) (_Any other))")
+    );
+    assert_eq!(
+        Ok(Token::Atom("two".to_owned())),
+        eval("(@match (+ 1 1) (1 one) (2 two))")
+    );
+}
+
+#[test]
+fn should_match_inside_definitions() {
+    let mut engine = Engine::new();
+    engine
+        .parse_line("(@def (.area Shape) (@match Shape ((square S) (* S S)) ((rect W H) (+ W H))))")
+        .unwrap();
+
+    let res = engine
+        .parse_line("(.area (rect 2 3))")
+        .map(Token::unspanned);
+
+    assert_eq!(Ok(Token::Integer(5)), res)
+}
+
+#[test]
+fn error_when_no_pattern_matches() {
+    let res = eval("(@match (a b) ((c X) X))");
+
+    assert_eq!(
+        Err(LexError::NoMatch(Token::List(vec![
+            Token::Atom("a".to_owned()),
+            Token::Atom("b".to_owned()),
+        ]))),
+        res
+    )
+}
+
+#[test]
+fn error_on_malformed_arm() {
+    let res = eval("(@cond true)");
+
+    assert_eq!(
+        Err(LexError::TypeMismatch {
+            expected: "(test body)".to_owned(),
+            found: Token::Boolean(true),
+        }),
+        res.map_err(|err| match err {
+            LexError::TypeMismatch { expected, found } => LexError::TypeMismatch {
+                expected,
+                found: found.unspanned(),
+            },
+            err => err,
+        })
+    )
+}
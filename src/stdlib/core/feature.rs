@@ -1,54 +1,73 @@
 use super::Core;
 
+use crate::datalog::Relation;
+use crate::error::LexError;
 use crate::grammar::token::Token;
 use crate::state::State;
 use crate::utils::operation::UnaryOperation;
-use std::sync::Arc;
+use std::rc::Rc;
 
 impl Core {
-    pub fn return_value(state: &mut State, arg: Token) -> Token {
+    pub fn return_value(state: &mut State, arg: Token) -> Result<Token, LexError> {
         let mut op = UnaryOperation::new();
-        op.for_any(Box::new(|state, val| {
+        op.for_executed_any(Box::new(|state, val| {
             state._return(val.clone());
-            Some(val)
+            Ok(val)
         }));
 
-        match op.exec(state, arg) {
-            Some(val) => val,
-            _ => Token::_false(),
-        }
+        op.exec(state, arg)
     }
 
-    pub fn document(state: &mut State, arg: Token) -> Token {
+    pub fn document(state: &mut State, arg: Token) -> Result<Token, LexError> {
         if let Token::Document(_, content) = arg {
-            return state.exec(*content.clone());
+            return state.exec(*content);
         }
 
-        Token::_false()
+        Ok(Token::_false())
     }
 
-    pub fn def(state: &mut State, arg: Token) -> Token {
+    pub fn def(state: &mut State, arg: Token) -> Result<Token, LexError> {
         if let Token::Definition(source, target) = arg {
             use crate::definition::Definition;
-            let source = *source.clone();
-            let target = *target.clone();
+            // the body keeps its spans so errors raised by it point at the source
+            let source = source.unspanned();
+            let target = *target;
 
-            let borrowed_target = Arc::clone(&Arc::new(target.clone()));
+            let borrowed_target = Rc::new(target.clone());
             state.add_to_parent(
                 source.clone(),
                 Definition {
                     inp_sig: source.clone(),
-                    out_sig: target.clone(),
+                    out_sig: target,
                     res_sig: source,
-                    func: Arc::new(Box::new(move |state: &mut State, token: Token| -> Token {
-                        let out = borrowed_target.as_ref().clone();
+                    func: Rc::new(Box::new(move |state: &mut State, _: Token| {
+                        // bindings of the signature's variables flow into the body
+                        let out = state.bindings().resolve(borrowed_target.as_ref().clone());
                         state.exec(out)
                     })),
                 },
             );
-            return Token::_true();
+            return Ok(Token::_true());
         }
 
-        Token::_false()
+        Ok(Token::_false())
+    }
+
+    // `(@table (X .path Y))` memoizes the answers of every call to the
+    // relation of the pattern made while solving queries
+    pub fn table(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_any(Box::new(|state, pattern| match Relation::of(&pattern) {
+            Some(relation) => {
+                state.tables().declare(relation);
+                Ok(Token::_true())
+            }
+            None => Err(LexError::TypeMismatch {
+                expected: "list".to_owned(),
+                found: pattern.unspanned(),
+            }),
+        }));
+
+        op.exec(state, arg)
     }
 }
@@ -0,0 +1,46 @@
+use super::Core;
+
+use crate::closure::Closure;
+use crate::error::LexError;
+use crate::grammar::token::Token;
+use crate::state::State;
+use std::rc::Rc;
+
+impl Core {
+    // `(@fn (Params) body)` is a closure over the scope it is evaluated in
+    pub fn lambda(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let (params, body) = match arg.inner() {
+            Token::List(list) => (list[1].clone(), list[2].clone()),
+            _ => return Ok(arg),
+        };
+
+        let params = match params.inner() {
+            Token::List(params) => params.clone(),
+            _ => {
+                return Err(LexError::TypeMismatch {
+                    expected: "list of parameters".to_owned(),
+                    found: params,
+                })
+            }
+        };
+
+        let closure = Closure::new(params, body, state.clone());
+        Ok(Token::Closure(Rc::new(closure)))
+    }
+
+    // `(.call F args ...)` applies the closure `F` to the arguments
+    pub fn call(_: &mut State, arg: Token) -> Result<Token, LexError> {
+        let list = match arg.inner() {
+            Token::List(list) => list.clone(),
+            _ => return Ok(arg),
+        };
+
+        match list[1].inner() {
+            Token::Closure(closure) => closure.apply(&arg, list[2..].to_vec()),
+            _ => Err(LexError::TypeMismatch {
+                expected: "closure".to_owned(),
+                found: list[1].clone(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+use crate::{error::LexError, evaluator::Engine, grammar::token::Token};
+
+#[cfg(test)]
+fn eval(line: &str) -> Result<Token, LexError> {
+    Engine::new()
+        .parse_line(line)
+        .map(Token::unspanned)
+        .map_err(LexError::unspanned)
+}
+
+#[test]
+fn should_evaluate_fn_to_closure() {
+    let res = eval("(@fn (X) (+ X 1))");
+
+    assert!(matches!(res, Ok(Token::Closure(_))))
+}
+
+#[test]
+fn should_call_closure() {
+    assert_eq!(
+        Ok(Token::Integer(3)),
+        eval("(.call (@fn (X Y) (+ X Y)) 1 2)")
+    );
+}
+
+#[test]
+fn should_apply_closure_at_head_of_list() {
+    assert_eq!(Ok(Token::Integer(2)), eval("((@fn (X) (+ X 1)) 1)"));
+    assert_eq!(
+        Ok(Token::Integer(6)),
+        eval("(@let ((Inc (@fn (X) (+ X 1)))) (Inc (Inc 4)))")
+    );
+}
+
+#[test]
+fn should_pass_closure_to_definition() {
+    let mut engine = Engine::new();
+    engine
+        .parse_line("(@def (.twice F X) (.call F (.call F X)))")
+        .unwrap();
+
+    let res = engine
+        .parse_line("(.twice (@fn (N) (+ N 10)) 1)")
+        .map(Token::unspanned);
+
+    assert_eq!(Ok(Token::Integer(21)), res)
+}
+
+#[test]
+fn should_return_closure_capturing_arguments() {
+    let mut engine = Engine::new();
+    engine
+        .parse_line("(@def (.adder N) (@fn (X) (+ X N)))")
+        .unwrap();
+
+    let res = engine.parse_line("((.adder 5) 2)").map(Token::unspanned);
+
+    assert_eq!(Ok(Token::Integer(7)), res)
+}
+
+#[test]
+fn should_see_definitions_of_defining_scope() {
+    let mut engine = Engine::new();
+    engine.parse_line("(@def (.ten) 10)").unwrap();
+
+    let res = engine
+        .parse_line("(.call (@fn () (.ten)))")
+        .map(Token::unspanned);
+
+    assert_eq!(Ok(Token::Integer(10)), res)
+}
+
+#[test]
+fn should_destructure_parameters() {
+    assert_eq!(
+        Ok(Token::Integer(3)),
+        eval("(.call (@fn ((pair A B)) (+ A B)) (pair 1 2))")
+    );
+}
+
+#[test]
+fn error_on_wrong_number_of_arguments() {
+    let res = eval("(.call (@fn (X Y) X) 1)");
+
+    assert!(matches!(
+        res,
+        Err(LexError::Arity {
+            expected: 2,
+            found: 1,
+            ..
+        })
+    ))
+}
+
+#[test]
+fn error_on_calling_non_closure() {
+    let res = eval("(.call 1 2)");
+
+    assert!(matches!(
+        res,
+        Err(LexError::TypeMismatch { expected, .. }) if expected == "closure"
+    ))
+}
@@ -0,0 +1,42 @@
+use super::Core;
+
+use crate::error::LexError;
+use crate::grammar::token::Token;
+use crate::state::State;
+use crate::stdlib::{holds, raw_operands};
+
+impl Core {
+    // `(@and a b ...)` evaluates its operands in order until one is false,
+    // it is the last value evaluated, true without operands
+    pub fn and(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut res = Token::_true();
+        for operand in raw_operands(&arg) {
+            res = state.exec(operand)?;
+            if !holds(&res) {
+                break;
+            }
+        }
+
+        Ok(res)
+    }
+
+    // `(@or a b ...)` evaluates its operands in order until one is not
+    // false, it is that value, false if there is none
+    pub fn or(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        for operand in raw_operands(&arg) {
+            let res = state.exec(operand)?;
+            if holds(&res) {
+                return Ok(res);
+            }
+        }
+
+        Ok(Token::_false())
+    }
+
+    // `(@do a b ...)` has its operands evaluated in order, it is the last
+    // one, the signature asks for at least one
+    pub fn sequence(_: &mut State, arg: Token) -> Result<Token, LexError> {
+        let operands = raw_operands(&arg);
+        Ok(operands[operands.len() - 1].clone())
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+use crate::{error::LexError, evaluator::Engine, grammar::token::Token};
+
+#[cfg(test)]
+fn eval(line: &str) -> Result<Token, LexError> {
+    Engine::new()
+        .parse_line(line)
+        .map(Token::unspanned)
+        .map_err(LexError::unspanned)
+}
+
+#[test]
+fn should_and_any_number_of_operands() {
+    assert_eq!(Ok(Token::_true()), eval("(@and)"));
+    assert_eq!(Ok(Token::_true()), eval("(@and true (< 1 2) true)"));
+    assert_eq!(Ok(Token::_false()), eval("(@and true false true)"));
+    assert_eq!(Ok(Token::Integer(3)), eval("(@and 1 2 3)"));
+}
+
+#[test]
+fn should_or_any_number_of_operands() {
+    assert_eq!(Ok(Token::_false()), eval("(@or)"));
+    assert_eq!(Ok(Token::_false()), eval("(@or false (> 1 2))"));
+    assert_eq!(Ok(Token::Integer(2)), eval("(@or false 2 3)"));
+}
+
+#[test]
+fn should_short_circuit() {
+    // the undefined call would fail if it was evaluated
+    assert_eq!(Ok(Token::_false()), eval("(@and false (.undefined))"));
+    assert_eq!(Ok(Token::_true()), eval("(@or true (.undefined))"));
+}
+
+#[test]
+fn should_return_last_value_of_do() {
+    assert_eq!(Ok(Token::Integer(3)), eval("(@do 1 2 (+ 1 2))"));
+}
+
+#[test]
+fn should_sequence_definitions_in_do() {
+    let res = eval("(@do (@def (.two) 2) (+ (.two) 1))");
+
+    assert_eq!(Ok(Token::Integer(3)), res)
+}
+
+#[test]
+fn should_divide_integers() {
+    assert_eq!(Ok(Token::Integer(3)), eval("(@div 7 2)"));
+    assert_eq!(Ok(Token::Integer(-3)), eval("(@div -7 2)"));
+    assert_eq!(Ok(Token::Integer(2)), eval("(@div (+ 4 1) 2)"));
+}
+
+#[test]
+fn error_on_integer_division_by_zero() {
+    let res = eval("(@div 1 0)");
+
+    assert_eq!(
+        Err(LexError::DivisionByZero(Token::List(vec![
+            Token::Keyword("div".to_owned()),
+            Token::Integer(1),
+            Token::Integer(0),
+        ]))),
+        res.map_err(|err| match err {
+            LexError::DivisionByZero(call) => LexError::DivisionByZero(call.unspanned()),
+            err => err,
+        })
+    )
+}
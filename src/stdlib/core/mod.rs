@@ -1,10 +1,24 @@
 pub mod arithmetic;
+pub mod arithmetic_tests;
+pub mod binding;
+pub mod binding_tests;
+pub mod comparison;
+pub mod comparison_tests;
+pub mod conditional;
+pub mod conditional_tests;
 pub mod feature;
+pub mod function;
+pub mod function_tests;
+pub mod logic;
+pub mod logic_tests;
+pub mod query;
+pub mod select;
+pub mod select_tests;
 
 use crate::definition::Definition;
 use crate::grammar::token::Token;
 use crate::modules::Module;
-use std::sync::Arc;
+use std::rc::Rc;
 
 pub struct Core;
 
@@ -20,7 +34,7 @@ impl Module for Core {
                 ]),
                 out_sig: Token::Value,
                 res_sig: Token::Value,
-                func: Arc::new(Box::new(Self::add)),
+                func: Rc::new(Box::new(Self::add)),
             },
             Definition {
                 inp_sig: Token::List(vec![
@@ -30,7 +44,7 @@ impl Module for Core {
                 ]),
                 out_sig: Token::Value,
                 res_sig: Token::Value,
-                func: Arc::new(Box::new(Self::min)),
+                func: Rc::new(Box::new(Self::min)),
             },
             Definition {
                 inp_sig: Token::List(vec![
@@ -40,7 +54,7 @@ impl Module for Core {
                 ]),
                 out_sig: Token::Value,
                 res_sig: Token::Value,
-                func: Arc::new(Box::new(Self::div)),
+                func: Rc::new(Box::new(Self::div)),
             },
             Definition {
                 inp_sig: Token::List(vec![
@@ -50,26 +64,226 @@ impl Module for Core {
                 ]),
                 out_sig: Token::Value,
                 res_sig: Token::Value,
-                func: Arc::new(Box::new(Self::div)),
+                func: Rc::new(Box::new(Self::multiply)),
+            },
+            Definition {
+                inp_sig: Token::List(vec![
+                    Token::Keyword("div".to_owned()),
+                    Token::Value,
+                    Token::Value,
+                ]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::int_div)),
+            },
+            // logic
+            Definition {
+                inp_sig: Token::ListOperation(Box::new(Token::Keyword("and".to_owned())), vec![]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::and)),
+            },
+            Definition {
+                inp_sig: Token::ListOperation(Box::new(Token::Keyword("or".to_owned())), vec![]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::or)),
+            },
+            Definition {
+                inp_sig: Token::ListOperation(
+                    Box::new(Token::Keyword("do".to_owned())),
+                    vec![Token::Value],
+                ),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::sequence)),
+            },
+            // conditionals
+            Definition {
+                inp_sig: Token::List(vec![
+                    Token::Keyword("if".to_owned()),
+                    Token::Value,
+                    Token::Value,
+                ]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::if_then)),
+            },
+            Definition {
+                inp_sig: Token::List(vec![
+                    Token::Keyword("if".to_owned()),
+                    Token::Value,
+                    Token::Value,
+                    Token::Value,
+                ]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::if_then)),
+            },
+            Definition {
+                inp_sig: Token::ListOperation(Box::new(Token::Keyword("cond".to_owned())), vec![]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::cond)),
+            },
+            Definition {
+                inp_sig: Token::ListOperation(
+                    Box::new(Token::Keyword("match".to_owned())),
+                    vec![Token::Value],
+                ),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::match_value)),
+            },
+            // comparison
+            Definition {
+                inp_sig: Token::List(vec![
+                    Token::Operator("=".to_owned()),
+                    Token::Value,
+                    Token::Value,
+                ]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::eq)),
+            },
+            Definition {
+                inp_sig: Token::List(vec![
+                    Token::Operator("!=".to_owned()),
+                    Token::Value,
+                    Token::Value,
+                ]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::ne)),
+            },
+            Definition {
+                inp_sig: Token::List(vec![
+                    Token::Operator("<".to_owned()),
+                    Token::Value,
+                    Token::Value,
+                ]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::lt)),
+            },
+            Definition {
+                inp_sig: Token::List(vec![
+                    Token::Operator(">".to_owned()),
+                    Token::Value,
+                    Token::Value,
+                ]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::gt)),
+            },
+            Definition {
+                inp_sig: Token::List(vec![
+                    Token::Operator("<=".to_owned()),
+                    Token::Value,
+                    Token::Value,
+                ]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::le)),
+            },
+            Definition {
+                inp_sig: Token::List(vec![
+                    Token::Operator(">=".to_owned()),
+                    Token::Value,
+                    Token::Value,
+                ]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::ge)),
+            },
+            // queries
+            Definition {
+                inp_sig: Token::List(vec![Token::Operator("?".to_owned()), Token::Value]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::query)),
+            },
+            Definition {
+                inp_sig: Token::List(vec![Token::Operator("!".to_owned()), Token::Value]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::not)),
+            },
+            Definition {
+                inp_sig: Token::List(vec![
+                    Token::Identifier("select".to_owned()),
+                    Token::Value,
+                    Token::Value,
+                ]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::select)),
+            },
+            Definition {
+                inp_sig: Token::List(vec![
+                    Token::Identifier("select".to_owned()),
+                    Token::Value,
+                    Token::Value,
+                    Token::Value,
+                ]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::select)),
             },
             // features
             Definition {
                 inp_sig: Token::Document(Box::new(Token::Value), Box::new(Token::Value)),
                 out_sig: Token::Value,
                 res_sig: Token::Value,
-                func: Arc::new(Box::new(Self::document)),
+                func: Rc::new(Box::new(Self::document)),
             },
             Definition {
                 inp_sig: Token::Definition(Box::new(Token::Value), Box::new(Token::Value)),
                 out_sig: Token::Value,
                 res_sig: Token::Value,
-                func: Arc::new(Box::new(Self::def)),
+                func: Rc::new(Box::new(Self::def)),
+            },
+            Definition {
+                inp_sig: Token::List(vec![
+                    Token::Keyword("let".to_owned()),
+                    Token::Value,
+                    Token::Value,
+                ]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::let_in)),
+            },
+            // functions
+            Definition {
+                inp_sig: Token::List(vec![
+                    Token::Keyword("fn".to_owned()),
+                    Token::Value,
+                    Token::Value,
+                ]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::lambda)),
+            },
+            Definition {
+                inp_sig: Token::ListOperation(
+                    Box::new(Token::Identifier("call".to_owned())),
+                    vec![Token::Value],
+                ),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::call)),
+            },
+            Definition {
+                inp_sig: Token::List(vec![Token::Keyword("table".to_owned()), Token::Value]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::table)),
             },
             Definition {
                 inp_sig: Token::List(vec![Token::Keyword("return".to_owned()), Token::Value]),
                 out_sig: Token::Value,
                 res_sig: Token::Value,
-                func: Arc::new(Box::new(Self::return_value)),
+                func: Rc::new(Box::new(Self::return_value)),
             },
         ];
 
@@ -0,0 +1,56 @@
+use super::Core;
+
+use crate::error::LexError;
+use crate::grammar::token::Token;
+use crate::solver::Solver;
+use crate::state::State;
+use crate::utils::operation::UnaryOperation;
+use crate::utils::unify;
+
+impl Core {
+    // `(? goal)` is whether a goal without variables is provable, for a goal
+    // with variables it is the list of its solutions, each one a list of
+    // `Variable: value` pairs, evaluating an answer again leaves it as it is
+    pub fn query(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_any(Box::new(|state, goal| {
+            let solver = Solver::new(state.clone());
+            let names = unify::variables(&goal);
+            if names.is_empty() {
+                return Ok(Token::Boolean(solver.any(&goal)?));
+            }
+
+            let answers = solver
+                .all(&goal)?
+                .into_iter()
+                .map(|answer| {
+                    let bindings = names
+                        .iter()
+                        .map(|name| {
+                            let value = answer.get(name).cloned().unwrap_or(Token::Value);
+                            Token::KeywordArg(name.clone(), Box::new(value))
+                        })
+                        .collect();
+
+                    Token::List(bindings)
+                })
+                .collect();
+
+            Ok(Token::List(answers))
+        }));
+
+        op.exec(state, arg)
+    }
+
+    // `(! goal)` is negation as failure, true if the goal has no solution
+    pub fn not(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_any(Box::new(|state, goal| {
+            let provable = Solver::new(state.clone()).any(&goal)?;
+
+            Ok(Token::Boolean(!provable))
+        }));
+
+        op.exec(state, arg)
+    }
+}
@@ -0,0 +1,51 @@
+use super::Core;
+
+use crate::error::LexError;
+use crate::grammar::token::Token;
+use crate::solver::Solver;
+use crate::state::State;
+
+impl Core {
+    // `(.select Columns from: Goal where: Filter)` is a row for every
+    // solution of the goal that passes the filter, goals can join relations
+    // with `@and` and filters can use any builtin, e.g. comparisons, either
+    // part may be left out or given positionally as `(.select Columns Goal)`
+    pub fn select(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let list = match arg.inner() {
+            Token::List(list) => list.clone(),
+            _ => return Err(LexError::Unresolved(arg)),
+        };
+
+        let columns = list[1].clone();
+        let mut goals = vec![Token::Keyword("and".to_owned())];
+        for item in list[2..].iter() {
+            match item.inner() {
+                Token::KeywordArg(name, _) if name != "from" && name != "where" => {
+                    return Err(LexError::Unresolved(arg))
+                }
+                Token::KeywordArg(_, _) => (),
+                _ => goals.push(item.clone()),
+            }
+        }
+        goals.extend(
+            ["from", "where"]
+                .iter()
+                .filter_map(|name| arg.named(name))
+                .cloned(),
+        );
+        let goal = Token::List(goals);
+
+        let mut rows = vec![];
+        Solver::new(state.clone()).solve(&goal, &mut |subst| {
+            let row = match subst.resolve(columns.clone()).unspanned() {
+                Token::List(row) => row,
+                value => vec![value],
+            };
+
+            rows.push(Token::List(row));
+            true
+        })?;
+
+        Ok(Token::List(rows))
+    }
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+use crate::{evaluator::Engine, grammar::token::Token};
+
+#[cfg(test)]
+const STAFF: &str = r#"
+@document staff
+(@dec (10 .name alice))
+(@dec (11 .name bob))
+(@dec (12 .name carol))
+(@dec (10 .team core))
+(@dec (12 .team web))
+(@dec (11 .team core))
+"#;
+
+#[cfg(test)]
+fn select(line: &str) -> Token {
+    let mut engine = Engine::new();
+    engine.parse(STAFF).unwrap();
+
+    engine.parse_line(line).unwrap().unspanned()
+}
+
+#[cfg(test)]
+fn row(values: Vec<Token>) -> Token {
+    Token::List(values)
+}
+
+#[cfg(test)]
+fn atom(name: &str) -> Token {
+    Token::Atom(name.to_owned())
+}
+
+#[test]
+fn should_project_columns() {
+    let res = select("(.select (ID Name) (ID .name Name))");
+
+    assert_eq!(
+        Token::List(vec![
+            row(vec![Token::Integer(10), atom("alice")]),
+            row(vec![Token::Integer(11), atom("bob")]),
+            row(vec![Token::Integer(12), atom("carol")]),
+        ]),
+        res
+    )
+}
+
+#[test]
+fn should_select_single_column() {
+    let res = select("(.select Name (10 .name Name))");
+
+    assert_eq!(Token::List(vec![row(vec![atom("alice")])]), res)
+}
+
+#[test]
+fn should_join_relations() {
+    let res = select("(.select (Name Team) (@and (ID .name Name) (ID .team Team)))");
+
+    assert_eq!(
+        Token::List(vec![
+            row(vec![atom("alice"), atom("core")]),
+            row(vec![atom("bob"), atom("core")]),
+            row(vec![atom("carol"), atom("web")]),
+        ]),
+        res
+    )
+}
+
+#[test]
+fn should_filter_joined_rows() {
+    let res = select("(.select Name from: (ID .name Name) where: (ID .team web))");
+
+    assert_eq!(Token::List(vec![row(vec![atom("carol")])]), res)
+}
+
+#[test]
+fn should_return_no_rows() {
+    let res = select("(.select Name where: (ID .team ops) from: (ID .name Name))");
+
+    assert_eq!(Token::List(vec![]), res)
+}
+
+#[test]
+fn should_select_with_where_only() {
+    let res = select("(.select (ID Team) where: (ID .team core))");
+
+    assert_eq!(
+        Token::List(vec![
+            row(vec![Token::Integer(10), atom("core")]),
+            row(vec![Token::Integer(11), atom("core")]),
+        ]),
+        res
+    )
+}
+
+#[test]
+fn should_not_run_calls_stored_in_rows() {
+    let mut engine = Engine::new();
+    engine.parse_line("(@include \"fmt\")").unwrap();
+    engine.parse_line("(@include \"os\")").unwrap();
+    engine.parse_line("(@dec (cmd (.exit 3)))").unwrap();
+
+    let res = engine.parse_line("(.println (.select (X) from: (cmd X)))");
+
+    let exit = Token::List(vec![
+        Token::Identifier("exit".to_owned()),
+        Token::Integer(3),
+    ]);
+    assert_eq!(
+        Ok(Token::List(vec![row(vec![exit])])),
+        res.map(Token::unspanned)
+    )
+}
@@ -1,35 +1,249 @@
-use super::Module;
+use super::{operands, Module};
 use crate::definition::Definition;
+use crate::error::LexError;
 use crate::grammar::token::Token;
 use crate::state::State;
 use crate::utils::operation::UnaryOperation;
-use std::sync::Arc;
+use std::io::Write;
+use std::rc::Rc;
 
 pub struct Fmt;
 
 impl Module for Fmt {
     fn load(&self) -> Vec<Definition> {
-        vec![Definition {
-            inp_sig: Token::List(vec![Token::Identifier("println".to_owned()), Token::Value]),
-            out_sig: Token::Value,
-            res_sig: Token::Value,
-            func: Arc::new(Box::new(Self::println)),
-        }]
+        vec![
+            Definition {
+                inp_sig: Token::List(vec![Token::Identifier("println".to_owned()), Token::Value]),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::println)),
+            },
+            Definition {
+                inp_sig: Token::ListOperation(
+                    Box::new(Token::Identifier("format".to_owned())),
+                    vec![Token::Value],
+                ),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::format)),
+            },
+            Definition {
+                inp_sig: Token::ListOperation(
+                    Box::new(Token::Identifier("printf".to_owned())),
+                    vec![Token::Value],
+                ),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::printf)),
+            },
+        ]
+    }
+}
+
+// `[[fill]align][width][.precision][?]` part of a placeholder after `:`
+#[derive(Debug, Default)]
+pub struct Spec {
+    fill: Option<char>,
+    align: Option<char>,
+    width: usize,
+    precision: Option<usize>,
+    debug: bool,
+}
+
+impl Spec {
+    pub fn parse(spec: &str) -> Result<Self, LexError> {
+        let mut res = Spec::default();
+        let mut chars: Vec<char> = spec.chars().collect();
+
+        let align = |c: &char| ['<', '>', '^'].contains(c);
+        if chars.len() >= 2 && align(&chars[1]) {
+            res.fill = Some(chars[0]);
+            res.align = Some(chars[1]);
+            chars.drain(..2);
+        } else if chars.first().is_some_and(align) {
+            res.align = Some(chars.remove(0));
+        }
+
+        let digits = |chars: &mut Vec<char>| -> Option<usize> {
+            let len = chars.iter().take_while(|c| c.is_ascii_digit()).count();
+            let number: String = chars.drain(..len).collect();
+            number.parse().ok()
+        };
+
+        res.width = digits(&mut chars).unwrap_or(0);
+        if chars.first() == Some(&'.') {
+            chars.remove(0);
+            res.precision = match digits(&mut chars) {
+                Some(precision) => Some(precision),
+                None => {
+                    return Err(LexError::Format(format!(
+                        "missing precision in {{:{}}}",
+                        spec
+                    )))
+                }
+            };
+        }
+
+        if chars.first() == Some(&'?') {
+            chars.remove(0);
+            res.debug = true;
+        }
+
+        match chars.is_empty() {
+            true => Ok(res),
+            false => Err(LexError::Format(format!(
+                "unknown format spec {{:{}}}",
+                spec
+            ))),
+        }
+    }
+
+    pub fn render(&self, token: &Token) -> String {
+        let text = match (token.inner(), self.precision) {
+            (token, _) if self.debug => format!("{} {}", token.kind(), token),
+            (Token::Number(n), Some(precision)) => format!("{:.*}", precision, n),
+            (Token::Integer(n), Some(precision)) => format!("{:.*}", precision, *n as f64),
+            (Token::String(s), Some(precision)) => s.chars().take(precision).collect(),
+            (Token::String(s), None) => s.clone(),
+            (token, _) => token.to_string(),
+        };
+
+        let len = text.chars().count();
+        if len >= self.width {
+            return text;
+        }
+
+        // numbers line up on the right unless told otherwise
+        let default = match token.inner() {
+            Token::Number(_) | Token::Integer(_) => '>',
+            _ => '<',
+        };
+        let pad = self.width - len;
+        let (left, right) = match self.align.unwrap_or(default) {
+            '>' => (pad, 0),
+            '^' => (pad / 2, pad - pad / 2),
+            _ => (0, pad),
+        };
+
+        let fill = self.fill.unwrap_or(' ').to_string();
+        fill.repeat(left) + &text + &fill.repeat(right)
+    }
+}
+
+// `template` with every placeholder replaced, `{}` takes the next
+// positional argument, `{0}` the one at an index and `{name}` the value of
+// the `name:` argument, `{{` and `}}` are literal braces
+pub fn format(
+    template: &str,
+    args: &[Token],
+    named: &[(String, Token)],
+) -> Result<String, LexError> {
+    let mut res = String::new();
+    let mut next = 0;
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                res.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                res.push('}');
+            }
+            '}' => return Err(LexError::Format("unmatched } in format string".to_owned())),
+            '{' => {
+                let mut field = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => field.push(c),
+                        None => {
+                            return Err(LexError::Format(
+                                "unterminated { in format string".to_owned(),
+                            ))
+                        }
+                    }
+                }
+
+                let (name, spec) = field.split_once(':').unwrap_or((&field, ""));
+                let value = match name {
+                    "" => {
+                        next += 1;
+                        args.get(next - 1)
+                    }
+                    name if name.chars().all(|c| c.is_ascii_digit()) => {
+                        name.parse().ok().and_then(|i: usize| args.get(i))
+                    }
+                    name => named
+                        .iter()
+                        .find(|(key, _)| key == name)
+                        .map(|(_, value)| value),
+                };
+
+                match value {
+                    Some(value) => res += &Spec::parse(spec)?.render(value),
+                    None => return Err(LexError::Format(format!("no argument for {{{}}}", field))),
+                }
+            }
+            c => res.push(c),
+        }
+    }
+
+    Ok(res)
+}
+
+// format the operands of a `.format` or `.printf` call
+fn format_call(state: &mut State, arg: &Token) -> Result<String, LexError> {
+    let mut args = vec![];
+    let mut named = vec![];
+    for operand in operands(state, arg)? {
+        match operand {
+            Token::KeywordArg(name, value) => named.push((name, *value)),
+            operand => args.push(operand),
+        }
+    }
+
+    if args.is_empty() {
+        return Err(LexError::Format("missing format string".to_owned()));
+    }
+
+    match args.remove(0) {
+        Token::String(template) => format(&template, &args, &named),
+        template => Err(LexError::TypeMismatch {
+            expected: "string".to_owned(),
+            found: template,
+        }),
     }
 }
 
 impl Fmt {
-    fn println(state: &mut State, arg: Token) -> Token {
+    fn println(state: &mut State, arg: Token) -> Result<Token, LexError> {
         let mut op = UnaryOperation::new();
         op.for_executed_any(Box::new(|_, token| {
             println!("{}", token.clone());
 
-            Some(token)
+            Ok(token)
         }));
 
-        match op.exec(state, arg) {
-            Some(val) => val,
-            _ => Token::_false(),
-        }
+        op.exec(state, arg)
+    }
+
+    // `(.format "Hello {}, you are {:.2}" Name Age)` is the formatted string
+    fn format(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        Ok(Token::String(format_call(state, &arg)?))
+    }
+
+    // like `.format` but the string is also printed, without a line ending
+    fn printf(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let res = format_call(state, &arg)?;
+
+        let mut stdout = std::io::stdout();
+        write!(stdout, "{}", res)
+            .and_then(|_| stdout.flush())
+            .map_err(|err| LexError::io("<stdout>", err))?;
+
+        Ok(Token::String(res))
     }
 }
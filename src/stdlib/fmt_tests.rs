@@ -0,0 +1,115 @@
+#[cfg(test)]
+use super::fmt::{format, Spec};
+#[cfg(test)]
+use crate::{error::LexError, evaluator::Engine, grammar::token::Token};
+
+#[cfg(test)]
+fn eval(line: &str) -> Result<Token, LexError> {
+    let mut engine = Engine::new();
+    engine.parse_line("(@include \"fmt\")").unwrap();
+
+    engine
+        .parse_line(line)
+        .map(Token::unspanned)
+        .map_err(LexError::unspanned)
+}
+
+#[cfg(test)]
+fn string(s: &str) -> Token {
+    Token::String(s.to_owned())
+}
+
+#[test]
+fn should_fill_positional_placeholders() {
+    let res = eval("(.format \"Hello {}, you are {:.2}\" \"Ann\" 41.5)");
+
+    assert_eq!(Ok(string("Hello Ann, you are 41.50")), res)
+}
+
+#[test]
+fn should_fill_indexed_and_named_placeholders() {
+    let res = eval("(.format \"{1} {0} {name}\" a b name: (+ 1 2))");
+
+    assert_eq!(Ok(string("b a 3")), res)
+}
+
+#[test]
+fn should_escape_braces() {
+    assert_eq!(Ok(string("{1}")), eval("(.format \"{{{}}}\" 1)"));
+}
+
+#[test]
+fn should_align_within_width() {
+    assert_eq!(Ok("ab   ".to_owned()), format("{:5}", &[string("ab")], &[]));
+    assert_eq!(
+        Ok("   12".to_owned()),
+        format("{:5}", &[Token::Integer(12)], &[])
+    );
+    assert_eq!(
+        Ok("*ab**".to_owned()),
+        format("{:*^5}", &[string("ab")], &[])
+    );
+    assert_eq!(
+        Ok("12   ".to_owned()),
+        format("{:<5}", &[Token::Integer(12)], &[])
+    );
+}
+
+#[test]
+fn should_truncate_strings_to_precision() {
+    assert_eq!(
+        Ok("hel".to_owned()),
+        format("{:.3}", &[string("hello")], &[])
+    );
+}
+
+#[test]
+fn should_show_kind_in_debug_form() {
+    assert_eq!(
+        Ok(string("string \"a\" / list (1 b)")),
+        eval("(.format \"{:?} / {:?}\" \"a\" (1 b))")
+    );
+}
+
+#[test]
+fn should_parse_full_spec() {
+    let spec = Spec::parse("0>8.3").unwrap();
+
+    assert_eq!("0012.346", spec.render(&Token::Number(12.3456)))
+}
+
+#[test]
+fn should_printf_formatted_string() {
+    assert_eq!(Ok(string("x=1")), eval("(.printf \"x={}\" 1)"));
+}
+
+#[test]
+fn error_on_missing_argument() {
+    let res = eval("(.format \"{} {}\" 1)");
+
+    assert_eq!(Err(LexError::Format("no argument for {}".to_owned())), res)
+}
+
+#[test]
+fn error_on_missing_template() {
+    let res = eval("(.format name: 1)");
+
+    assert_eq!(
+        Err(LexError::Format("missing format string".to_owned())),
+        res
+    )
+}
+
+#[test]
+fn error_on_malformed_template() {
+    assert_eq!(
+        Err(LexError::Format(
+            "unterminated { in format string".to_owned()
+        )),
+        format("{", &[], &[])
+    );
+    assert_eq!(
+        Err(LexError::Format("unknown format spec {:x}".to_owned())),
+        format("{:x}", &[Token::Integer(1)], &[])
+    );
+}
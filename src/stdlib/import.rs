@@ -1,4 +1,5 @@
 use crate::definition::Definition;
+use crate::error::LexError;
 use crate::grammar::document;
 use crate::grammar::token::Token;
 use crate::handler::Handler;
@@ -6,56 +7,58 @@ use crate::modules::Module;
 use crate::state::State;
 use crate::utils::operation::UnaryOperation;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::rc::Rc;
 
 pub struct Import {
-    modules: Arc<HashMap<String, Box<dyn Module>>>,
+    modules: Rc<HashMap<String, Box<dyn Module>>>,
 }
 
 impl Import {
     pub fn new(modules: HashMap<String, Box<dyn Module>>) -> Self {
         Self {
-            modules: Arc::new(modules),
+            modules: Rc::new(modules),
         }
     }
 
     pub fn import(&self) -> Box<Handler> {
-        let modules = Arc::clone(&self.modules);
+        let modules = Rc::clone(&self.modules);
         let mut op = UnaryOperation::new();
         op.for_string(Box::new(move |state, str| {
             let m = &modules;
 
-            match m.get(&str) {
-                Some(module) => {
-                    state.include(module.as_ref());
-
-                    Some(Token::_true())
+            if let Some(module) = m.get(&str) {
+                if let Some(capability) = module.capability() {
+                    if !state.allows(capability) {
+                        return Err(LexError::Denied(capability.to_owned()));
+                    }
                 }
-                None => {
-                    if let Ok(input) = std::fs::read_to_string(&str) {
-                        let parse = document(&input);
 
-                        let mut document = Token::_true();
-                        match parse {
-                            Ok((_, token)) => document = token,
-                            Err(_) => (),
-                        };
+                state.include(module.as_ref());
 
-                        state.exec(document);
-                        Some(Token::_true())
-                    } else {
-                        Some(Token::_false())
-                    }
-                }
+                return Ok(Token::_true());
             }
-        }));
 
-        Box::new(move |state: &mut State, token: Token| -> Token {
-            match op.exec(state, token) {
-                Some(val) => val,
-                _ => Token::_false(),
+            // reading a file is io like any other
+            if !state.allows("io") {
+                return Err(LexError::Denied("io".to_owned()));
             }
-        })
+
+            Self::import_file(state, &str).map_err(|err| LexError::Import {
+                path: str.clone(),
+                reason: Box::new(err),
+            })
+        }));
+
+        Box::new(move |state: &mut State, token: Token| op.exec(state, token))
+    }
+
+    fn import_file(state: &mut State, path: &str) -> Result<Token, LexError> {
+        let input = std::fs::read_to_string(path).map_err(|err| LexError::io(path, err))?;
+
+        let document = document(&input).map_err(|errors| LexError::parse_all(&input, errors))?;
+
+        state.exec(document)?;
+        Ok(Token::_true())
     }
 }
 
@@ -65,7 +68,7 @@ impl Module for Import {
             inp_sig: Token::List(vec![Token::Keyword("include".to_owned()), Token::Value]),
             out_sig: Token::Value,
             res_sig: Token::Value,
-            func: Arc::new(self.import()),
+            func: Rc::new(self.import()),
         }]
     }
 }
@@ -0,0 +1,162 @@
+use super::{definition, operands, string, Module};
+use crate::definition::Definition;
+use crate::error::LexError;
+use crate::grammar::token::Token;
+use crate::state::State;
+use crate::utils::operation::UnaryOperation;
+use std::io::{BufRead, Write};
+
+// files and standard streams, needs the `io` capability
+pub struct Io;
+
+impl Module for Io {
+    fn load(&self) -> Vec<Definition> {
+        vec![
+            definition("read_file", 1, Box::new(Self::read_file)),
+            definition("write_file", 2, Box::new(Self::write_file)),
+            definition("append_file", 2, Box::new(Self::append_file)),
+            definition("read_line", 0, Box::new(Self::read_line)),
+            definition("print", 1, Box::new(Self::print)),
+            definition("eprintln", 1, Box::new(Self::eprintln)),
+            definition("list_dir", 1, Box::new(Self::list_dir)),
+            definition("exists", 1, Box::new(Self::exists)),
+            definition("is_dir", 1, Box::new(Self::is_dir)),
+        ]
+    }
+
+    fn capability(&self) -> Option<&'static str> {
+        Some("io")
+    }
+}
+
+// strings are written as they are, anything else as it is printed
+fn text(token: &Token) -> String {
+    match token.inner() {
+        Token::String(s) => s.clone(),
+        token => token.to_string(),
+    }
+}
+
+// write `content` to the file at `path`, after what it has if `append`
+fn write(path: &str, content: &str, append: bool) -> Result<Token, LexError> {
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .append(append)
+        .truncate(!append)
+        .open(path)
+        .map_err(|err| LexError::io(path, err))?;
+
+    file.write_all(content.as_bytes())
+        .map_err(|err| LexError::io(path, err))?;
+
+    Ok(Token::_true())
+}
+
+impl Io {
+    fn read_file(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_string(Box::new(|_, path| {
+            let content = std::fs::read_to_string(&path).map_err(|err| LexError::io(&path, err))?;
+
+            Ok(Token::String(content))
+        }));
+
+        op.exec(state, arg)
+    }
+
+    // `(.write_file Path Content)` replaces the content of the file
+    fn write_file(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let operands = operands(state, &arg)?;
+
+        write(&string(&operands[0])?, &text(&operands[1]), false)
+    }
+
+    fn append_file(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let operands = operands(state, &arg)?;
+
+        write(&string(&operands[0])?, &text(&operands[1]), true)
+    }
+
+    // next line of the standard input without its line ending, false once
+    // the input is exhausted
+    fn read_line(_: &mut State, _: Token) -> Result<Token, LexError> {
+        let mut line = String::new();
+        let read = std::io::stdin()
+            .lock()
+            .read_line(&mut line)
+            .map_err(|err| LexError::io("<stdin>", err))?;
+
+        if read == 0 {
+            return Ok(Token::_false());
+        }
+
+        let len = line.trim_end_matches(['\n', '\r']).len();
+        line.truncate(len);
+
+        Ok(Token::String(line))
+    }
+
+    // like `.println` without the line ending
+    fn print(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_executed_any(Box::new(|_, token| {
+            let mut stdout = std::io::stdout();
+            write!(stdout, "{}", token)
+                .and_then(|_| stdout.flush())
+                .map_err(|err| LexError::io("<stdout>", err))?;
+
+            Ok(token)
+        }));
+
+        op.exec(state, arg)
+    }
+
+    fn eprintln(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_executed_any(Box::new(|_, token| {
+            eprintln!("{}", token);
+
+            Ok(token)
+        }));
+
+        op.exec(state, arg)
+    }
+
+    // names of the entries of a directory, sorted
+    fn list_dir(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_string(Box::new(|_, path| {
+            let entries = std::fs::read_dir(&path).map_err(|err| LexError::io(&path, err))?;
+
+            let mut names = vec![];
+            for entry in entries {
+                let entry = entry.map_err(|err| LexError::io(&path, err))?;
+                names.push(entry.file_name().to_string_lossy().into_owned());
+            }
+            names.sort();
+
+            Ok(Token::List(names.into_iter().map(Token::String).collect()))
+        }));
+
+        op.exec(state, arg)
+    }
+
+    fn exists(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_string(Box::new(|_, path| {
+            Ok(Token::Boolean(std::path::Path::new(&path).exists()))
+        }));
+
+        op.exec(state, arg)
+    }
+
+    fn is_dir(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_string(Box::new(|_, path| {
+            Ok(Token::Boolean(std::path::Path::new(&path).is_dir()))
+        }));
+
+        op.exec(state, arg)
+    }
+}
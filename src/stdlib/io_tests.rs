@@ -0,0 +1,109 @@
+#[cfg(test)]
+use crate::{error::LexError, evaluator::Engine, grammar::token::Token};
+
+#[cfg(test)]
+fn engine() -> Engine {
+    let mut engine = Engine::new();
+    engine.parse_line("(@include \"io\")").unwrap();
+
+    engine
+}
+
+// fresh directory for a single test
+#[cfg(test)]
+fn scratch(name: &str) -> String {
+    let dir = std::env::temp_dir().join(format!("lex-io-{}-{}", std::process::id(), name));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    dir.to_string_lossy().into_owned()
+}
+
+#[test]
+fn should_write_append_and_read_files() {
+    let dir = scratch("files");
+    let mut engine = engine();
+
+    engine
+        .parse_line(&format!("(.write_file \"{}/a.txt\" \"hello\")", dir))
+        .unwrap();
+    engine
+        .parse_line(&format!("(.append_file \"{}/a.txt\" \" world\")", dir))
+        .unwrap();
+    let res = engine
+        .parse_line(&format!("(.read_file \"{}/a.txt\")", dir))
+        .map(Token::unspanned);
+
+    assert_eq!(Ok(Token::String("hello world".to_owned())), res)
+}
+
+#[test]
+fn should_list_directory_and_check_existence() {
+    let dir = scratch("dir");
+    std::fs::write(format!("{}/b", dir), "").unwrap();
+    std::fs::write(format!("{}/a", dir), "").unwrap();
+    let mut engine = engine();
+
+    let res = engine
+        .parse_line(&format!("(.list_dir \"{}\")", dir))
+        .map(Token::unspanned);
+    assert_eq!(
+        Ok(Token::List(vec![
+            Token::String("a".to_owned()),
+            Token::String("b".to_owned()),
+        ])),
+        res
+    );
+
+    let exists = |engine: &mut Engine, line: String| engine.parse_line(&line).map(Token::unspanned);
+    assert_eq!(
+        Ok(Token::_true()),
+        exists(&mut engine, format!("(.exists \"{}/a\")", dir))
+    );
+    assert_eq!(
+        Ok(Token::_false()),
+        exists(&mut engine, format!("(.exists \"{}/c\")", dir))
+    );
+    assert_eq!(
+        Ok(Token::_true()),
+        exists(&mut engine, format!("(.is_dir \"{}\")", dir))
+    );
+}
+
+#[test]
+fn error_on_missing_file() {
+    let dir = scratch("missing");
+
+    let res = engine()
+        .parse_line(&format!("(.read_file \"{}/none.txt\")", dir))
+        .map_err(LexError::unspanned);
+
+    assert!(matches!(res, Err(LexError::Io { path, .. }) if path.ends_with("none.txt")))
+}
+
+#[test]
+fn error_on_include_without_capability() {
+    let mut engine = Engine::new();
+    engine.set_capability("io", false);
+
+    let res = engine
+        .parse_line("(@include \"io\")")
+        .map_err(LexError::unspanned);
+
+    assert_eq!(Err(LexError::Denied("io".to_owned())), res)
+}
+
+#[test]
+fn error_on_including_file_without_capability() {
+    let dir = scratch("include");
+    std::fs::write(format!("{}/lib.lx", dir), "@document lib\n(@def (.one) 1)").unwrap();
+
+    let mut engine = Engine::new();
+    engine.set_capability("io", false);
+
+    let res = engine
+        .parse_line(&format!("(@include \"{}/lib.lx\")", dir))
+        .map_err(LexError::unspanned);
+
+    assert_eq!(Err(LexError::Denied("io".to_owned())), res)
+}
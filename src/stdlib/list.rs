@@ -0,0 +1,254 @@
+use super::{definition, holds, operands, Module};
+use crate::definition::Definition;
+use crate::error::LexError;
+use crate::grammar::token::Token;
+use crate::state::State;
+use crate::utils::compare;
+use crate::utils::operation::{BinaryOperation, UnaryOperation};
+use std::cmp::Ordering;
+
+pub struct List;
+
+impl Module for List {
+    fn load(&self) -> Vec<Definition> {
+        vec![
+            definition("map", 2, Box::new(Self::map)),
+            definition("filter", 2, Box::new(Self::filter)),
+            definition("fold", 3, Box::new(Self::fold)),
+            definition("len", 1, Box::new(Self::len)),
+            definition("head", 1, Box::new(Self::head)),
+            definition("tail", 1, Box::new(Self::tail)),
+            definition("nth", 2, Box::new(Self::nth)),
+            definition("concat", 2, Box::new(Self::concat)),
+            definition("reverse", 1, Box::new(Self::reverse)),
+            definition("zip", 2, Box::new(Self::zip)),
+            definition("range", 1, Box::new(Self::range)),
+            definition("range", 2, Box::new(Self::range)),
+            definition("sort", 1, Box::new(Self::sort)),
+            definition("uniq", 1, Box::new(Self::uniq)),
+        ]
+    }
+}
+
+fn list(token: Token) -> Result<Vec<Token>, LexError> {
+    match token.inner() {
+        Token::List(list) => Ok(list.clone()),
+        _ => Err(LexError::TypeMismatch {
+            expected: "list".to_owned(),
+            found: token,
+        }),
+    }
+}
+
+fn non_empty(list: Vec<Token>) -> Result<Vec<Token>, LexError> {
+    match list.is_empty() {
+        true => Err(LexError::TypeMismatch {
+            expected: "non-empty list".to_owned(),
+            found: Token::List(list),
+        }),
+        false => Ok(list),
+    }
+}
+
+impl List {
+    // `(.map F List)` is `F` applied to every item
+    fn map(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = BinaryOperation::new();
+        op.for_any(Box::new(|state, (func, items)| {
+            let func = state.exec(func)?;
+            let items = list(state.exec(items)?)?;
+
+            let res = items
+                .into_iter()
+                .map(|item| state.apply(func.clone(), vec![item]))
+                .collect::<Result<_, _>>()?;
+
+            Ok(Token::List(res))
+        }));
+
+        op.exec(state, arg)
+    }
+
+    // `(.filter F List)` keeps the items `F` does not give false for
+    fn filter(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = BinaryOperation::new();
+        op.for_any(Box::new(|state, (func, items)| {
+            let func = state.exec(func)?;
+
+            let mut res = vec![];
+            for item in list(state.exec(items)?)? {
+                if holds(&state.apply(func.clone(), vec![item.clone()])?) {
+                    res.push(item);
+                }
+            }
+
+            Ok(Token::List(res))
+        }));
+
+        op.exec(state, arg)
+    }
+
+    // `(.fold F Init List)` combines the items from the left, starting with
+    // `Init`, as `(F accumulated item)`
+    fn fold(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut operands = operands(state, &arg)?;
+        let items = list(operands.remove(2))?;
+        let func = operands.remove(0);
+
+        let mut res = operands.remove(0);
+        for item in items {
+            res = state.apply(func.clone(), vec![res, item])?;
+        }
+
+        Ok(res)
+    }
+
+    fn len(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_list(Box::new(|_, list| Ok(Token::Integer(list.len() as i64))));
+
+        op.exec(state, arg)
+    }
+
+    fn head(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_list(Box::new(|_, list| Ok(non_empty(list)?.remove(0))));
+
+        op.exec(state, arg)
+    }
+
+    // every item but the first
+    fn tail(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_list(Box::new(|_, list| {
+            Ok(Token::List(non_empty(list)?.split_off(1)))
+        }));
+
+        op.exec(state, arg)
+    }
+
+    // `(.nth List N)` is the item at zero based index `N`
+    fn nth(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let operands = operands(state, &arg)?;
+        let items = list(operands[0].clone())?;
+
+        let index = match operands[1].inner() {
+            Token::Integer(n) if *n >= 0 => *n as usize,
+            index => {
+                return Err(LexError::TypeMismatch {
+                    expected: "non-negative integer".to_owned(),
+                    found: index.clone(),
+                })
+            }
+        };
+
+        match items.get(index) {
+            Some(item) => Ok(item.clone()),
+            None => Err(LexError::OutOfRange(arg.unspanned())),
+        }
+    }
+
+    fn concat(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = BinaryOperation::new();
+        op.for_list(Box::new(|_, (mut a, b)| {
+            a.extend(b);
+            Ok(Token::List(a))
+        }));
+
+        op.exec(state, arg)
+    }
+
+    fn reverse(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_list(Box::new(|_, mut list| {
+            list.reverse();
+            Ok(Token::List(list))
+        }));
+
+        op.exec(state, arg)
+    }
+
+    // pairs of the items at the same index, as long as the shorter list
+    fn zip(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = BinaryOperation::new();
+        op.for_list(Box::new(|_, (a, b)| {
+            let pairs = a
+                .into_iter()
+                .zip(b)
+                .map(|(x, y)| Token::List(vec![x, y]))
+                .collect();
+
+            Ok(Token::List(pairs))
+        }));
+
+        op.exec(state, arg)
+    }
+
+    // `(.range End)` or `(.range Start End)` counts up by one from `Start`,
+    // zero by default, to `End` excluded
+    fn range(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let operands = operands(state, &arg)?;
+
+        let mut bounds = vec![];
+        for operand in operands.iter() {
+            match operand.inner() {
+                Token::Integer(n) => bounds.push(*n),
+                operand => {
+                    return Err(LexError::TypeMismatch {
+                        expected: "integer".to_owned(),
+                        found: operand.clone(),
+                    })
+                }
+            }
+        }
+
+        let (start, end) = match bounds[..] {
+            [end] => (0, end),
+            [start, end] => (start, end),
+            _ => return Err(LexError::Unresolved(arg)),
+        };
+
+        Ok(Token::List((start..end).map(Token::Integer).collect()))
+    }
+
+    // ascending, in the order `<` compares values
+    fn sort(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_list(Box::new(|_, mut list| {
+            let mut comparable = true;
+            list.sort_by(|a, b| {
+                compare::compare(a, b).unwrap_or_else(|| {
+                    comparable = false;
+                    Ordering::Equal
+                })
+            });
+
+            match comparable {
+                true => Ok(Token::List(list)),
+                false => Err(LexError::TypeMismatch {
+                    expected: "list of comparable values".to_owned(),
+                    found: Token::List(list),
+                }),
+            }
+        }));
+
+        op.exec(state, arg)
+    }
+
+    // the first occurrence of every distinct item
+    fn uniq(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_list(Box::new(|_, list| {
+            let mut res: Vec<Token> = vec![];
+            for item in list {
+                if !res.iter().any(|seen| compare::equal(seen, &item)) {
+                    res.push(item);
+                }
+            }
+
+            Ok(Token::List(res))
+        }));
+
+        op.exec(state, arg)
+    }
+}
@@ -0,0 +1,149 @@
+#[cfg(test)]
+use crate::{error::LexError, evaluator::Engine, grammar::token::Token};
+
+#[cfg(test)]
+fn engine() -> Engine {
+    let mut engine = Engine::new();
+    engine.parse_line("(@include \"list\")").unwrap();
+
+    engine
+}
+
+#[cfg(test)]
+fn eval(line: &str) -> Result<Token, LexError> {
+    engine()
+        .parse_line(line)
+        .map(Token::unspanned)
+        .map_err(LexError::unspanned)
+}
+
+#[cfg(test)]
+fn integers(list: &[i64]) -> Token {
+    Token::List(list.iter().map(|n| Token::Integer(*n)).collect())
+}
+
+#[test]
+fn should_only_be_available_once_included() {
+    let res = Engine::new()
+        .parse_line("(.len (1 2))")
+        .map_err(LexError::unspanned);
+
+    assert!(matches!(res, Err(LexError::Unresolved(_))))
+}
+
+#[test]
+fn should_map_with_closure() {
+    let res = eval("(.map (@fn (X) (+ X 1)) (1 2 3))");
+
+    assert_eq!(Ok(integers(&[2, 3, 4])), res)
+}
+
+#[test]
+fn should_keep_closures_returned_by_map_as_data() {
+    let res = eval("(.len (.map (@fn (X) (@fn (Y) X)) (.range 2)))");
+
+    assert_eq!(Ok(Token::Integer(2)), res)
+}
+
+#[test]
+fn should_call_closures_returned_by_map() {
+    let res = eval("(.map (@fn (F) (.call F 10)) (.map (@fn (X) (@fn (Y) (+ X Y))) (1 2)))");
+
+    assert_eq!(Ok(integers(&[11, 12])), res)
+}
+
+#[test]
+fn should_map_with_definition() {
+    let mut engine = engine();
+    engine.parse_line("(@def (.double X) (+ X X))").unwrap();
+
+    let res = engine
+        .parse_line("(.map .double (1 2 3))")
+        .map(Token::unspanned);
+
+    assert_eq!(Ok(integers(&[2, 4, 6])), res)
+}
+
+#[test]
+fn should_filter() {
+    let res = eval("(.filter (@fn (X) (> X 1)) (1 2 3))");
+
+    assert_eq!(Ok(integers(&[2, 3])), res)
+}
+
+#[test]
+fn should_fold_from_the_left() {
+    assert_eq!(Ok(Token::Integer(6)), eval("(.fold + 0 (1 2 3))"));
+    assert_eq!(
+        Ok(integers(&[3, 2, 1])),
+        eval("(.fold (@fn (Acc X) (.concat (X) Acc)) () (1 2 3))")
+    );
+}
+
+#[test]
+fn should_access_items() {
+    assert_eq!(Ok(Token::Integer(3)), eval("(.len (1 2 3))"));
+    assert_eq!(Ok(Token::Integer(1)), eval("(.head (1 2 3))"));
+    assert_eq!(Ok(integers(&[2, 3])), eval("(.tail (1 2 3))"));
+    assert_eq!(Ok(Token::Integer(3)), eval("(.nth (1 2 3) 2)"));
+}
+
+#[test]
+fn should_combine_lists() {
+    assert_eq!(Ok(integers(&[1, 2, 3])), eval("(.concat (1) (2 3))"));
+    assert_eq!(Ok(integers(&[3, 2, 1])), eval("(.reverse (1 2 3))"));
+    assert_eq!(
+        Ok(Token::List(vec![integers(&[1, 3]), integers(&[2, 4])])),
+        eval("(.zip (1 2) (3 4 5))")
+    );
+}
+
+#[test]
+fn should_count_range() {
+    assert_eq!(Ok(integers(&[0, 1, 2])), eval("(.range 3)"));
+    assert_eq!(Ok(integers(&[2, 3])), eval("(.range 2 4)"));
+    assert_eq!(Ok(integers(&[])), eval("(.range 4 2)"));
+}
+
+#[test]
+fn should_sort_and_remove_duplicates() {
+    assert_eq!(Ok(integers(&[1, 2, 3])), eval("(.sort (3 1 2))"));
+    assert_eq!(Ok(integers(&[3, 1, 2])), eval("(.uniq (3 1 3 2 1))"));
+}
+
+#[test]
+fn error_on_head_of_empty_list() {
+    let res = eval("(.head ())");
+
+    assert!(matches!(
+        res,
+        Err(LexError::TypeMismatch { expected, .. }) if expected == "non-empty list"
+    ))
+}
+
+#[test]
+fn error_on_index_out_of_range() {
+    let res = eval("(.nth (1 2) 2)");
+
+    assert!(matches!(res, Err(LexError::OutOfRange(_))))
+}
+
+#[test]
+fn error_on_sorting_incomparable_values() {
+    let res = eval("(.sort (1 \"one\"))");
+
+    assert!(matches!(
+        res,
+        Err(LexError::TypeMismatch { expected, .. }) if expected == "list of comparable values"
+    ))
+}
+
+#[test]
+fn error_on_mapping_with_non_callable() {
+    let res = eval("(.map 1 (1 2))");
+
+    assert!(matches!(
+        res,
+        Err(LexError::TypeMismatch { expected, .. }) if expected == "closure or definition name"
+    ))
+}
@@ -0,0 +1,206 @@
+use super::{constant, definition, Module};
+use crate::definition::Definition;
+use crate::error::LexError;
+use crate::grammar::token::Token;
+use crate::state::State;
+use crate::utils::operation::{BinaryOperation, UnaryOperation};
+
+// numeric functions, `.pi` and `.e` are values, e.g. `(.sin .pi)`
+pub struct Math;
+
+impl Module for Math {
+    fn load(&self) -> Vec<Definition> {
+        vec![
+            constant("pi", Token::Number(std::f64::consts::PI)),
+            constant("e", Token::Number(std::f64::consts::E)),
+            definition("sqrt", 1, Box::new(Self::sqrt)),
+            definition("pow", 2, Box::new(Self::pow)),
+            definition("abs", 1, Box::new(Self::abs)),
+            definition("floor", 1, Box::new(Self::floor)),
+            definition("ceil", 1, Box::new(Self::ceil)),
+            definition("round", 1, Box::new(Self::round)),
+            definition("min", 2, Box::new(Self::min)),
+            definition("max", 2, Box::new(Self::max)),
+            definition("mod", 2, Box::new(Self::modulo)),
+            definition("sin", 1, Box::new(Self::sin)),
+            definition("cos", 1, Box::new(Self::cos)),
+            definition("tan", 1, Box::new(Self::tan)),
+            definition("asin", 1, Box::new(Self::asin)),
+            definition("acos", 1, Box::new(Self::acos)),
+            definition("atan", 1, Box::new(Self::atan)),
+            definition("atan2", 2, Box::new(Self::atan2)),
+            definition("exp", 1, Box::new(Self::exp)),
+            definition("log", 1, Box::new(Self::ln)),
+            definition("log", 2, Box::new(Self::log)),
+            definition("log2", 1, Box::new(Self::log2)),
+            definition("log10", 1, Box::new(Self::log10)),
+        ]
+    }
+}
+
+// function of a single number
+fn unary(func: fn(f64) -> f64) -> UnaryOperation {
+    let mut op = UnaryOperation::new();
+    op.for_number(Box::new(move |_, n| Ok(Token::Number(func(n)))));
+
+    op
+}
+
+// rounding to a whole number, an integer when it fits, integers are kept
+// as they are
+fn whole(func: fn(f64) -> f64) -> UnaryOperation {
+    let mut op = UnaryOperation::new();
+    op.for_integer(Box::new(|_, n| Ok(Token::Integer(n))))
+        .for_number(Box::new(move |_, n| {
+            let n = func(n);
+            match n.is_finite() && n.abs() < i64::MAX as f64 {
+                true => Ok(Token::Integer(n as i64)),
+                false => Ok(Token::Number(n)),
+            }
+        }));
+
+    op
+}
+
+// function of two numbers
+fn binary(func: fn(f64, f64) -> f64) -> BinaryOperation {
+    let mut op = BinaryOperation::new();
+    op.for_number(Box::new(move |_, (a, b)| Ok(Token::Number(func(a, b)))));
+
+    op
+}
+
+impl Math {
+    fn sqrt(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        unary(f64::sqrt).exec(state, arg)
+    }
+
+    // whole exponents are multiplied out rather than going through logs
+    fn pow(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = binary(
+            |a, b| match b.fract() == 0.0 && b.abs() <= i32::MAX as f64 {
+                true => a.powi(b as i32),
+                false => a.powf(b),
+            },
+        );
+        // an integer to a non-negative integer power stays an integer
+        op.for_integer(Box::new(|_, (a, b)| {
+            let exact = u32::try_from(b).ok().and_then(|b| a.checked_pow(b));
+            match exact {
+                Some(n) => Ok(Token::Integer(n)),
+                None => Ok(Token::Number((a as f64).powf(b as f64))),
+            }
+        }));
+
+        op.exec(state, arg)
+    }
+
+    fn abs(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = unary(f64::abs);
+        op.for_integer(Box::new(|_, n| match n.checked_abs() {
+            Some(n) => Ok(Token::Integer(n)),
+            None => Ok(Token::Number((n as f64).abs())),
+        }));
+
+        op.exec(state, arg)
+    }
+
+    fn floor(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        whole(f64::floor).exec(state, arg)
+    }
+
+    fn ceil(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        whole(f64::ceil).exec(state, arg)
+    }
+
+    // halfway cases are rounded away from zero
+    fn round(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        whole(f64::round).exec(state, arg)
+    }
+
+    fn min(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = binary(f64::min);
+        op.for_integer(Box::new(|_, (a, b)| Ok(Token::Integer(a.min(b)))));
+
+        op.exec(state, arg)
+    }
+
+    fn max(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = binary(f64::max);
+        op.for_integer(Box::new(|_, (a, b)| Ok(Token::Integer(a.max(b)))));
+
+        op.exec(state, arg)
+    }
+
+    // `(.mod A B)` is the remainder of `A` divided by `B`, never negative
+    fn modulo(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let call = arg.clone();
+        let zero = call.clone();
+        let mut op = BinaryOperation::new();
+        op.for_integer(Box::new(move |_, (a, b)| match a.checked_rem_euclid(b) {
+            Some(n) => Ok(Token::Integer(n)),
+            None if b == 0 => Err(LexError::DivisionByZero(zero.clone())),
+            // only `i64::MIN` by `-1` overflows, which leaves nothing
+            None => Ok(Token::Integer(0)),
+        }))
+        .for_number(Box::new(move |_, (a, b)| {
+            if b == 0.0 {
+                return Err(LexError::DivisionByZero(call.clone()));
+            }
+
+            Ok(Token::Number(a.rem_euclid(b)))
+        }));
+
+        op.exec(state, arg)
+    }
+
+    fn sin(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        unary(f64::sin).exec(state, arg)
+    }
+
+    fn cos(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        unary(f64::cos).exec(state, arg)
+    }
+
+    fn tan(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        unary(f64::tan).exec(state, arg)
+    }
+
+    fn asin(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        unary(f64::asin).exec(state, arg)
+    }
+
+    fn acos(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        unary(f64::acos).exec(state, arg)
+    }
+
+    fn atan(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        unary(f64::atan).exec(state, arg)
+    }
+
+    // `(.atan2 Y X)` is the angle of the point `(X, Y)`
+    fn atan2(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        binary(f64::atan2).exec(state, arg)
+    }
+
+    fn exp(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        unary(f64::exp).exec(state, arg)
+    }
+
+    fn ln(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        unary(f64::ln).exec(state, arg)
+    }
+
+    // `(.log X Base)`
+    fn log(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        binary(f64::log).exec(state, arg)
+    }
+
+    fn log2(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        unary(f64::log2).exec(state, arg)
+    }
+
+    fn log10(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        unary(f64::log10).exec(state, arg)
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+use crate::{error::LexError, evaluator::Engine, grammar::token::Token};
+
+#[cfg(test)]
+fn eval(line: &str) -> Result<Token, LexError> {
+    let mut engine = Engine::new();
+    engine.parse_line("(@include \"math\")").unwrap();
+
+    engine
+        .parse_line(line)
+        .map(Token::unspanned)
+        .map_err(LexError::unspanned)
+}
+
+#[cfg(test)]
+fn number(line: &str) -> f64 {
+    match eval(line) {
+        Ok(Token::Number(n)) => n,
+        res => panic!("expected a number, found {:?}", res),
+    }
+}
+
+#[cfg(test)]
+fn integer(line: &str) -> i64 {
+    match eval(line) {
+        Ok(Token::Integer(n)) => n,
+        res => panic!("expected an integer, found {:?}", res),
+    }
+}
+
+#[test]
+fn should_provide_constants() {
+    assert_eq!(std::f64::consts::PI, number(".pi"));
+    assert_eq!(std::f64::consts::E, number(".e"));
+    assert_eq!(2.0 * std::f64::consts::PI, number("(* 2 .pi)"));
+}
+
+#[test]
+fn should_compute_powers_and_roots() {
+    assert_eq!(3.0, number("(.sqrt 9)"));
+    assert_eq!(1024, integer("(.pow 2 10)"));
+    assert_eq!(0.25, number("(.pow 2 -2)"));
+    assert_eq!(2.0, number("(.pow 4 0.5)"));
+}
+
+#[test]
+fn should_round_numbers() {
+    assert_eq!(2, integer("(.abs -2)"));
+    assert_eq!(2.5, number("(.abs -2.5)"));
+    assert_eq!(1, integer("(.floor 1.7)"));
+    assert_eq!(-1, integer("(.ceil -1.7)"));
+    assert_eq!(3, integer("(.round 2.5)"));
+}
+
+#[test]
+fn should_pick_min_and_max() {
+    assert_eq!(1, integer("(.min 1 2)"));
+    assert_eq!(1.5, number("(.min 1.5 2)"));
+    assert_eq!(2, integer("(.max 1 2)"));
+}
+
+#[test]
+fn should_take_non_negative_modulo() {
+    assert_eq!(1, integer("(.mod 7 3)"));
+    assert_eq!(2, integer("(.mod -7 3)"));
+    assert_eq!(0.5, number("(.mod 2.5 2)"));
+}
+
+#[test]
+fn should_compute_trigonometry() {
+    assert!(number("(.sin .pi)").abs() < 1e-12);
+    assert_eq!(1.0, number("(.cos 0)"));
+    assert!((number("(.atan2 1 1)") - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+}
+
+#[test]
+fn should_compute_logarithms() {
+    assert_eq!(1.0, number("(.log .e)"));
+    assert_eq!(3.0, number("(.log2 8)"));
+    assert_eq!(2.0, number("(.log10 100)"));
+    assert!((number("(.log 27 3)") - 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn error_on_modulo_by_zero() {
+    let res = eval("(.mod 1 0)");
+
+    assert!(matches!(res, Err(LexError::DivisionByZero(_))))
+}
+
+#[test]
+fn error_on_non_number() {
+    let res = eval("(.sqrt \"4\")");
+
+    assert_eq!(
+        Err(LexError::TypeMismatch {
+            expected: "number".to_owned(),
+            found: Token::String("4".to_owned()),
+        }),
+        res
+    )
+}
@@ -1,13 +1,81 @@
 pub mod core;
 pub mod fmt;
+pub mod fmt_tests;
 pub mod import;
 pub mod io;
+pub mod io_tests;
+pub mod list;
+pub mod list_tests;
 pub mod math;
+pub mod math_tests;
 pub mod os;
+pub mod os_tests;
+pub mod string;
+pub mod string_tests;
 
 use crate::definition::Definition;
+use crate::error::LexError;
+use crate::grammar::token::Token;
+use crate::handler::Handler;
 use crate::modules::Module;
+use crate::state::State;
 use std::collections::HashMap;
+use std::rc::Rc;
+
+// `(.name $ ...)` with `arity` operands
+pub fn definition(name: &str, arity: usize, func: Box<Handler>) -> Definition {
+    let mut sig = vec![Token::Identifier(name.to_owned())];
+    sig.extend((0..arity).map(|_| Token::Value));
+
+    Definition {
+        inp_sig: Token::List(sig),
+        out_sig: Token::Value,
+        res_sig: Token::Value,
+        func: Rc::new(func),
+    }
+}
+
+// `.name` on its own, it evaluates to `value`
+pub fn constant(name: &str, value: Token) -> Definition {
+    Definition {
+        inp_sig: Token::Identifier(name.to_owned()),
+        out_sig: Token::Value,
+        res_sig: Token::Value,
+        func: Rc::new(Box::new(move |_: &mut State, _: Token| Ok(value.clone()))),
+    }
+}
+
+// every operand of the call, as it is written
+pub fn raw_operands(arg: &Token) -> Vec<Token> {
+    match arg.inner() {
+        Token::List(list) => list[1..].to_vec(),
+        _ => vec![],
+    }
+}
+
+// every operand of the call, evaluated
+pub fn operands(state: &mut State, arg: &Token) -> Result<Vec<Token>, LexError> {
+    raw_operands(arg)
+        .into_iter()
+        .map(|x| state.exec(x))
+        .collect()
+}
+
+// anything but false counts as true
+pub fn holds(token: &Token) -> bool {
+    !matches!(token.inner(), Token::Boolean(false))
+}
+
+// the text of a string operand
+pub fn string(token: &Token) -> Result<String, LexError> {
+    match token.inner() {
+        Token::String(s) => Ok(s.clone()),
+        token => Err(LexError::TypeMismatch {
+            expected: "string".to_owned(),
+            found: token.clone(),
+        }),
+    }
+}
 
 pub struct Std;
 
@@ -17,6 +85,11 @@ impl Module for Std {
 
         let mut hm: HashMap<String, Box<dyn Module>> = HashMap::new();
         hm.insert("fmt".to_owned(), Box::new(fmt::Fmt));
+        hm.insert("io".to_owned(), Box::new(io::Io));
+        hm.insert("list".to_owned(), Box::new(list::List));
+        hm.insert("math".to_owned(), Box::new(math::Math));
+        hm.insert("os".to_owned(), Box::new(os::Os));
+        hm.insert("string".to_owned(), Box::new(string::Str));
         let import = import::Import::new(hm);
         res.extend(import.load());
 
@@ -0,0 +1,103 @@
+use super::{definition, operands, string, Module};
+use crate::definition::Definition;
+use crate::error::LexError;
+use crate::grammar::token::Token;
+use crate::state::State;
+use crate::utils::operation::UnaryOperation;
+
+// process environment, needs the `os` capability
+pub struct Os;
+
+impl Module for Os {
+    fn load(&self) -> Vec<Definition> {
+        vec![
+            definition("args", 0, Box::new(Self::args)),
+            definition("env", 1, Box::new(Self::env)),
+            definition("set_env", 2, Box::new(Self::set_env)),
+            definition("cwd", 0, Box::new(Self::cwd)),
+            definition("exit", 1, Box::new(Self::exit)),
+            definition("spawn", 2, Box::new(Self::spawn)),
+        ]
+    }
+
+    fn capability(&self) -> Option<&'static str> {
+        Some("os")
+    }
+}
+
+impl Os {
+    // arguments given after `--` on the command line
+    fn args(state: &mut State, _: Token) -> Result<Token, LexError> {
+        let args = state.args().into_iter().map(Token::String).collect();
+
+        Ok(Token::List(args))
+    }
+
+    // value of an environment variable, false if it is not set
+    fn env(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_string(Box::new(|_, name| match std::env::var(&name) {
+            Ok(value) => Ok(Token::String(value)),
+            Err(_) => Ok(Token::_false()),
+        }));
+
+        op.exec(state, arg)
+    }
+
+    // `(.set_env Name Value)` for this process and the ones it spawns
+    fn set_env(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let operands = operands(state, &arg)?;
+        let (name, value) = (string(&operands[0])?, string(&operands[1])?);
+        std::env::set_var(name, value);
+
+        Ok(Token::_true())
+    }
+
+    fn cwd(_: &mut State, _: Token) -> Result<Token, LexError> {
+        let cwd = std::env::current_dir().map_err(|err| LexError::io(".", err))?;
+
+        Ok(Token::String(cwd.to_string_lossy().into_owned()))
+    }
+
+    // `(.exit Code)` stops the program with the status code
+    fn exit(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_integer(Box::new(|_, code| match i32::try_from(code) {
+            Ok(code) => Err(LexError::Exit(code)),
+            Err(_) => Err(LexError::OutOfRange(Token::Integer(code))),
+        }));
+
+        op.exec(state, arg)
+    }
+
+    // `(.spawn Program (Args ...))` runs a program to completion, it is
+    // `(status: Code stderr: "..." stdout: "...")`
+    fn spawn(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let operands = operands(state, &arg)?;
+        let program = string(&operands[0])?;
+        let args = match operands[1].inner() {
+            Token::List(list) => list.iter().map(string).collect::<Result<Vec<_>, _>>()?,
+            _ => {
+                return Err(LexError::TypeMismatch {
+                    expected: "list of strings".to_owned(),
+                    found: operands[1].clone(),
+                })
+            }
+        };
+
+        let output = std::process::Command::new(&program)
+            .args(args)
+            .output()
+            .map_err(|err| LexError::io(&program, err))?;
+
+        // killed by a signal when there is no code
+        let status = output.status.code().map_or(-1, i64::from);
+        let text = |bytes: Vec<u8>| Token::String(String::from_utf8_lossy(&bytes).into_owned());
+
+        Ok(Token::List(vec![
+            Token::KeywordArg("status".to_owned(), Box::new(Token::Integer(status))),
+            Token::KeywordArg("stderr".to_owned(), Box::new(text(output.stderr))),
+            Token::KeywordArg("stdout".to_owned(), Box::new(text(output.stdout))),
+        ]))
+    }
+}
@@ -0,0 +1,93 @@
+#[cfg(test)]
+use crate::{error::LexError, evaluator::Engine, grammar::token::Token};
+
+#[cfg(test)]
+fn engine() -> Engine {
+    let mut engine = Engine::new();
+    engine.parse_line("(@include \"os\")").unwrap();
+
+    engine
+}
+
+#[cfg(test)]
+fn eval(line: &str) -> Result<Token, LexError> {
+    engine()
+        .parse_line(line)
+        .map(Token::unspanned)
+        .map_err(LexError::unspanned)
+}
+
+#[test]
+fn should_give_program_arguments() {
+    let mut engine = engine();
+    engine.set_args(vec!["a".to_owned(), "b".to_owned()]);
+
+    let res = engine.parse_line("(.args)").map(Token::unspanned);
+
+    assert_eq!(
+        Ok(Token::List(vec![
+            Token::String("a".to_owned()),
+            Token::String("b".to_owned()),
+        ])),
+        res
+    )
+}
+
+#[test]
+fn should_set_and_get_environment_variables() {
+    assert_eq!(Ok(Token::_false()), eval("(.env \"LEX_OS_TEST_UNSET\")"));
+
+    let mut engine = engine();
+    engine
+        .parse_line("(.set_env \"LEX_OS_TEST\" \"value\")")
+        .unwrap();
+    let res = engine
+        .parse_line("(.env \"LEX_OS_TEST\")")
+        .map(Token::unspanned);
+
+    assert_eq!(Ok(Token::String("value".to_owned())), res)
+}
+
+#[test]
+fn should_give_working_directory() {
+    let cwd = std::env::current_dir().unwrap();
+
+    assert_eq!(
+        Ok(Token::String(cwd.to_string_lossy().into_owned())),
+        eval("(.cwd)")
+    )
+}
+
+#[test]
+fn should_exit_with_status_code() {
+    let res = engine().parse("@document test\n(.exit 3)\n(.println 1)");
+
+    assert_eq!(Some(3), res.unwrap_err().exit_code())
+}
+
+#[test]
+fn should_capture_output_of_spawned_process() {
+    let res = eval("(.spawn \"sh\" (\"-c\" \"echo out; echo err >&2; exit 2\"))");
+
+    assert_eq!(
+        Ok(Token::List(vec![
+            Token::KeywordArg("status".to_owned(), Box::new(Token::Integer(2))),
+            Token::KeywordArg(
+                "stderr".to_owned(),
+                Box::new(Token::String("err\n".to_owned()))
+            ),
+            Token::KeywordArg(
+                "stdout".to_owned(),
+                Box::new(Token::String("out\n".to_owned()))
+            ),
+        ])),
+        res
+    )
+}
+
+#[test]
+fn error_on_missing_program() {
+    let res = eval("(.spawn \"lex-no-such-program\" ())");
+
+    assert!(matches!(res, Err(LexError::Io { path, .. }) if path == "lex-no-such-program"))
+}
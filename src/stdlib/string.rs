@@ -0,0 +1,222 @@
+use super::{definition, operands, string, Module};
+use crate::definition::Definition;
+use crate::error::LexError;
+use crate::grammar::token::Token;
+use crate::state::State;
+use crate::utils::operation::{BinaryOperation, UnaryOperation};
+use std::rc::Rc;
+
+// string functions, `.length` and `.append` are named apart from the list
+// module's `.len` and `.concat` so both modules can be included together
+pub struct Str;
+
+impl Module for Str {
+    fn load(&self) -> Vec<Definition> {
+        vec![
+            Definition {
+                inp_sig: Token::ListOperation(
+                    Box::new(Token::Identifier("append".to_owned())),
+                    vec![Token::Value],
+                ),
+                out_sig: Token::Value,
+                res_sig: Token::Value,
+                func: Rc::new(Box::new(Self::append)),
+            },
+            definition("length", 1, Box::new(Self::length)),
+            definition("substring", 3, Box::new(Self::substring)),
+            definition("split", 2, Box::new(Self::split)),
+            definition("join", 2, Box::new(Self::join)),
+            definition("trim", 1, Box::new(Self::trim)),
+            definition("upper", 1, Box::new(Self::upper)),
+            definition("lower", 1, Box::new(Self::lower)),
+            definition("contains", 2, Box::new(Self::contains)),
+            definition("starts_with", 2, Box::new(Self::starts_with)),
+            definition("ends_with", 2, Box::new(Self::ends_with)),
+            definition("replace", 3, Box::new(Self::replace)),
+            definition("chars", 1, Box::new(Self::chars)),
+            definition("to_number", 1, Box::new(Self::to_number)),
+            definition("to_string", 1, Box::new(Self::to_string)),
+        ]
+    }
+}
+
+fn index(token: &Token) -> Result<usize, LexError> {
+    match token.inner() {
+        Token::Integer(n) if *n >= 0 => Ok(*n as usize),
+        token => Err(LexError::TypeMismatch {
+            expected: "non-negative integer".to_owned(),
+            found: token.clone(),
+        }),
+    }
+}
+
+// test on a string and a pattern
+fn predicate(test: fn(&str, &str) -> bool) -> BinaryOperation {
+    let mut op = BinaryOperation::new();
+    op.for_string(Box::new(move |_, (s, pattern)| {
+        Ok(Token::Boolean(test(&s, &pattern)))
+    }));
+
+    op
+}
+
+// change of a single string
+fn transform(change: fn(&str) -> String) -> UnaryOperation {
+    let mut op = UnaryOperation::new();
+    op.for_string(Box::new(move |_, s| Ok(Token::String(change(&s)))));
+
+    op
+}
+
+impl Str {
+    // `(.append A B ...)` concatenates any number of strings
+    fn append(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut res = String::new();
+        for operand in operands(state, &arg)? {
+            res += &string(&operand)?;
+        }
+
+        Ok(Token::String(res))
+    }
+
+    // number of characters, not of bytes
+    fn length(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_string(Box::new(|_, s| {
+            Ok(Token::Integer(s.chars().count() as i64))
+        }));
+
+        op.exec(state, arg)
+    }
+
+    // `(.substring S Start End)` is the characters from `Start` to `End`
+    // excluded
+    fn substring(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let operands = operands(state, &arg)?;
+        let s = string(&operands[0])?;
+        let (start, end) = (index(&operands[1])?, index(&operands[2])?);
+
+        if start > end || end > s.chars().count() {
+            return Err(LexError::OutOfRange(arg.unspanned()));
+        }
+
+        let res = s.chars().skip(start).take(end - start).collect();
+        Ok(Token::String(res))
+    }
+
+    // `(.split S Separator)` is the list of parts between separators
+    fn split(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = BinaryOperation::new();
+        op.for_string(Box::new(|_, (s, separator)| {
+            if separator.is_empty() {
+                return Err(LexError::TypeMismatch {
+                    expected: "non-empty separator".to_owned(),
+                    found: Token::String(separator),
+                });
+            }
+
+            let parts = s
+                .split(separator.as_str())
+                .map(|part| Token::String(part.to_owned()))
+                .collect();
+
+            Ok(Token::List(parts))
+        }));
+
+        op.exec(state, arg)
+    }
+
+    // `(.join List Separator)` concatenates a list of strings with the
+    // separator between them
+    fn join(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let operands = operands(state, &arg)?;
+        let separator = string(&operands[1])?;
+
+        let parts = match operands[0].inner() {
+            Token::List(list) => list.iter().map(string).collect::<Result<Vec<_>, _>>(),
+            _ => Err(LexError::TypeMismatch {
+                expected: "list of strings".to_owned(),
+                found: operands[0].clone(),
+            }),
+        }?;
+
+        Ok(Token::String(parts.join(&separator)))
+    }
+
+    fn trim(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        transform(|s| s.trim().to_owned()).exec(state, arg)
+    }
+
+    fn upper(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        transform(str::to_uppercase).exec(state, arg)
+    }
+
+    fn lower(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        transform(str::to_lowercase).exec(state, arg)
+    }
+
+    fn contains(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        predicate(|s, pattern| s.contains(pattern)).exec(state, arg)
+    }
+
+    fn starts_with(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        predicate(|s, pattern| s.starts_with(pattern)).exec(state, arg)
+    }
+
+    fn ends_with(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        predicate(|s, pattern| s.ends_with(pattern)).exec(state, arg)
+    }
+
+    // `(.replace S From To)` replaces every occurrence of `From`
+    fn replace(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let operands = operands(state, &arg)?;
+        let s = string(&operands[0])?;
+        let (from, to) = (string(&operands[1])?, string(&operands[2])?);
+
+        Ok(Token::String(s.replace(&from, &to)))
+    }
+
+    // list of the characters, each a string of its own
+    fn chars(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_string(Box::new(|_, s| {
+            let chars = s.chars().map(|c| Token::String(c.to_string())).collect();
+
+            Ok(Token::List(chars))
+        }));
+
+        op.exec(state, arg)
+    }
+
+    // an integer when the string is one, a float otherwise
+    fn to_number(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_string(Box::new(|_, s| {
+            let trimmed = s.trim();
+            if let Ok(n) = trimmed.parse::<i64>() {
+                return Ok(Token::Integer(n));
+            }
+
+            match trimmed.parse::<f64>() {
+                Ok(n) => Ok(Token::Number(n)),
+                Err(_) => Err(LexError::TypeMismatch {
+                    expected: "numeric string".to_owned(),
+                    found: Token::String(s),
+                }),
+            }
+        }));
+
+        op.exec(state, arg)
+    }
+
+    // any value as it is printed, strings are kept as they are
+    fn to_string(state: &mut State, arg: Token) -> Result<Token, LexError> {
+        let mut op = UnaryOperation::new();
+        op.for_executed_any(Box::new(|_, token| match token.inner() {
+            Token::String(_) => Ok(token),
+            token => Ok(Token::String(token.to_string())),
+        }));
+
+        op.exec(state, arg)
+    }
+}
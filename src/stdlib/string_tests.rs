@@ -0,0 +1,110 @@
+#[cfg(test)]
+use crate::{error::LexError, evaluator::Engine, grammar::token::Token};
+
+#[cfg(test)]
+fn eval(line: &str) -> Result<Token, LexError> {
+    let mut engine = Engine::new();
+    engine.parse_line("(@include \"string\")").unwrap();
+
+    engine
+        .parse_line(line)
+        .map(Token::unspanned)
+        .map_err(LexError::unspanned)
+}
+
+#[cfg(test)]
+fn string(s: &str) -> Token {
+    Token::String(s.to_owned())
+}
+
+#[test]
+fn should_append_strings() {
+    assert_eq!(Ok(string("abc")), eval("(.append \"a\" \"b\" \"c\")"));
+}
+
+#[test]
+fn should_count_characters() {
+    assert_eq!(Ok(Token::Integer(4)), eval("(.length \"héhé\")"));
+}
+
+#[test]
+fn should_take_substring() {
+    assert_eq!(Ok(string("ell")), eval("(.substring \"hello\" 1 4)"));
+}
+
+#[test]
+fn should_split_and_join() {
+    assert_eq!(
+        Ok(Token::List(vec![string("a"), string("b"), string("")])),
+        eval("(.split \"a,b,\" \",\")")
+    );
+    assert_eq!(Ok(string("a-b")), eval("(.join (\"a\" \"b\") \"-\")"));
+}
+
+#[test]
+fn should_change_case_and_trim() {
+    assert_eq!(Ok(string("hi")), eval("(.trim \"  hi \")"));
+    assert_eq!(Ok(string("HI")), eval("(.upper \"hi\")"));
+    assert_eq!(Ok(string("hi")), eval("(.lower \"HI\")"));
+}
+
+#[test]
+fn should_search_strings() {
+    assert_eq!(Ok(Token::_true()), eval("(.contains \"hello\" \"ll\")"));
+    assert_eq!(Ok(Token::_true()), eval("(.starts_with \"hello\" \"he\")"));
+    assert_eq!(Ok(Token::_false()), eval("(.ends_with \"hello\" \"he\")"));
+}
+
+#[test]
+fn should_replace_every_occurrence() {
+    assert_eq!(Ok(string("b-b")), eval("(.replace \"a-a\" \"a\" \"b\")"));
+}
+
+#[test]
+fn should_iterate_characters() {
+    assert_eq!(
+        Ok(Token::List(vec![string("a"), string("b")])),
+        eval("(.chars \"ab\")")
+    );
+}
+
+#[test]
+fn should_convert_numbers() {
+    assert_eq!(Ok(Token::Number(1.5)), eval("(.to_number \"1.5\")"));
+    assert_eq!(Ok(string("1.5")), eval("(.to_string 1.5)"));
+    assert_eq!(Ok(string("(a 1)")), eval("(.to_string (a 1))"));
+    assert_eq!(Ok(string("a")), eval("(.to_string \"a\")"));
+}
+
+#[test]
+fn should_include_with_list_module() {
+    let mut engine = Engine::new();
+    engine.parse_line("(@include \"list\")").unwrap();
+    engine.parse_line("(@include \"string\")").unwrap();
+
+    let res = engine
+        .parse_line("(.map .upper (.split \"a b\" \" \"))")
+        .map(Token::unspanned);
+
+    assert_eq!(Ok(Token::List(vec![string("A"), string("B")])), res)
+}
+
+#[test]
+fn error_on_substring_out_of_range() {
+    let res = eval("(.substring \"abc\" 1 4)");
+
+    assert!(matches!(res, Err(LexError::OutOfRange(_))))
+}
+
+#[test]
+fn error_on_non_numeric_string() {
+    let res = eval("(.to_number \"one\")");
+
+    assert_eq!(
+        Err(LexError::TypeMismatch {
+            expected: "numeric string".to_owned(),
+            found: string("one"),
+        }),
+        res
+    )
+}
@@ -0,0 +1,112 @@
+use crate::datalog::Relation;
+use crate::grammar::token::Token;
+use crate::utils::unify;
+use std::collections::{HashMap, HashSet};
+
+// answers found so far for one call pattern
+#[derive(Debug, Default)]
+pub struct Table {
+    answers: Vec<Token>,
+    seen: HashSet<String>,
+    complete: bool,
+    // round of the running evaluation the call was last evaluated in
+    round: usize,
+}
+
+// memoized answers of the relations declared with `@table`, kept apart for
+// every scope the calls are made from as they see different clauses, and
+// forgotten whenever a definition is added
+#[derive(Debug, Default)]
+pub struct Tables {
+    tabled: HashSet<Relation>,
+    tables: HashMap<(usize, String), Table>,
+    // round of the running evaluation, zero when none is running
+    round: usize,
+    changed: bool,
+}
+
+// what to do with a call to a tabled relation
+pub enum Lookup {
+    // answers are final or already being computed this round
+    Answers(Vec<Token>),
+    // the call has to be evaluated, within the running evaluation
+    Evaluate,
+    // the call has to be evaluated until no table grows anymore
+    Lead,
+}
+
+// key shared by calls equal up to the names of their variables
+pub fn variant(call: &Token) -> String {
+    let mut subst = unify::Substitution::new();
+    for (i, name) in unify::variables(call).iter().enumerate() {
+        subst.bind(name, Token::Variable(format!("#{}", i), None));
+    }
+
+    format!("{:?}", subst.resolve(call.clone()).unspanned())
+}
+
+impl Tables {
+    pub fn declare(&mut self, relation: Relation) {
+        self.tabled.insert(relation);
+    }
+
+    pub fn is_tabled(&self, call: &Token) -> bool {
+        Relation::of(call).is_some_and(|relation| self.tabled.contains(&relation))
+    }
+
+    // forget every answer, declarations are kept
+    pub fn clear(&mut self) {
+        self.tables.clear();
+    }
+
+    pub fn lookup(&mut self, scope: usize, call: &Token) -> Lookup {
+        let round = self.round;
+        let table = self.tables.entry((scope, variant(call))).or_default();
+
+        if table.complete || (round > 0 && table.round == round) {
+            return Lookup::Answers(table.answers.clone());
+        }
+
+        table.round = round;
+        match round {
+            0 => Lookup::Lead,
+            _ => Lookup::Evaluate,
+        }
+    }
+
+    // start a new round of the running evaluation
+    pub fn next_round(&mut self) {
+        self.round += 1;
+        self.changed = false;
+    }
+
+    // whether any table grew during the round
+    pub fn changed(&self) -> bool {
+        self.changed
+    }
+
+    pub fn answer(&mut self, scope: usize, call: &Token, answer: Token) {
+        let table = self.tables.entry((scope, variant(call))).or_default();
+        if table.seen.insert(format!("{:?}", answer)) {
+            table.answers.push(answer);
+            self.changed = true;
+        }
+    }
+
+    // end the running evaluation, its tables can no longer grow, on error
+    // the unfinished tables are dropped instead
+    pub fn finish(&mut self, complete: bool) {
+        self.round = 0;
+        self.tables.retain(|_, table| table.complete || complete);
+        for table in self.tables.values_mut() {
+            table.complete = true;
+        }
+    }
+
+    pub fn answers(&self, scope: usize, call: &Token) -> Vec<Token> {
+        self.tables
+            .get(&(scope, variant(call)))
+            .map(|table| table.answers.clone())
+            .unwrap_or_default()
+    }
+}
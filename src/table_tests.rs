@@ -0,0 +1,89 @@
+#[cfg(test)]
+use super::{
+    evaluator::Engine,
+    grammar::{repl_line, token::Token},
+    test_support::{atoms, engine, sorted_answers},
+};
+
+#[cfg(test)]
+const GRAPH: &str = r#"
+@document graph
+(@table (X .path Y))
+(@dec (a .to b))
+(@dec (b .to c))
+(@dec (c .to a))
+(@dec (c .to d))
+(@def (X .path Z) (@and (X .path Y) (Y .to Z)))
+(@def (X .path Y) (X .to Y))
+"#;
+
+#[test]
+fn should_terminate_on_left_recursion() {
+    let res = sorted_answers(&mut engine(GRAPH), "(a .path X)", "X");
+
+    assert_eq!(atoms(&["a", "b", "c", "d"]), res)
+}
+
+#[test]
+fn should_answer_every_call_pattern() {
+    let mut engine = engine(GRAPH);
+
+    let res = sorted_answers(&mut engine, "(X .path d)", "X");
+    assert_eq!(atoms(&["a", "b", "c"]), res);
+
+    let res = engine.parse_line("(? (d .path a))").unwrap();
+    assert_eq!(Token::_false(), res);
+}
+
+#[test]
+fn should_forget_answers_when_definitions_are_added() {
+    let mut engine = engine(GRAPH);
+    assert_eq!(atoms(&[]), sorted_answers(&mut engine, "(d .path X)", "X"));
+
+    engine.parse_line("(@dec (d .to e))").unwrap();
+    assert_eq!(
+        atoms(&["e"]),
+        sorted_answers(&mut engine, "(d .path X)", "X")
+    );
+}
+
+#[test]
+fn should_keep_answers_of_scopes_apart() {
+    let mut engine = engine(GRAPH);
+    let inner = engine
+        .parse_line("((@fn (Y) (@do (@dec (d .to Y)) (? (d .path X)))) e)")
+        .unwrap()
+        .unspanned();
+    let solution = Token::KeywordArg("X".to_owned(), Box::new(Token::Atom("e".to_owned())));
+    assert_eq!(Token::List(vec![Token::List(vec![solution])]), inner);
+
+    assert_eq!(atoms(&[]), sorted_answers(&mut engine, "(d .path X)", "X"));
+}
+
+#[test]
+fn should_not_table_undeclared_relations() {
+    let mut engine = engine(GRAPH);
+    engine
+        .parse_line("(@def (X .hop Z) (@and (X .to Y) (Y .to Z)))")
+        .unwrap();
+
+    let res = sorted_answers(&mut engine, "(a .hop X)", "X");
+
+    assert_eq!(atoms(&["c"]), res);
+    let goal = repl_line("(a .hop X)").unwrap();
+    assert!(!engine.state().tables().is_tabled(&goal));
+}
+
+#[test]
+fn should_table_atom_headed_relations_apart() {
+    let mut engine = Engine::new();
+    engine
+        .parse("@document reach (@table (reach X Y)) (@dec (edge a b))")
+        .unwrap();
+
+    let tabled = repl_line("(reach a X)").unwrap();
+    let plain = repl_line("(edge a X)").unwrap();
+
+    assert!(engine.state().tables().is_tabled(&tabled));
+    assert!(!engine.state().tables().is_tabled(&plain));
+}
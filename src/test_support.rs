@@ -0,0 +1,49 @@
+#[cfg(test)]
+use crate::{evaluator::Engine, grammar::token::Token, utils::unify::Substitution};
+
+// fixtures shared by the solver, table and datalog tests
+
+#[cfg(test)]
+pub fn engine(source: &str) -> Engine {
+    let mut engine = Engine::new();
+    engine.parse(source).unwrap();
+
+    engine
+}
+
+#[cfg(test)]
+pub fn datalog(source: &str) -> Engine {
+    let mut engine = Engine::new();
+    engine.set_datalog(true);
+    engine.parse(source).unwrap();
+
+    engine
+}
+
+// values `name` takes in the answers to `goal`, in the order they are found
+#[cfg(test)]
+pub fn answers(engine: &mut Engine, goal: &str, name: &str) -> Vec<Token> {
+    engine
+        .query(goal)
+        .unwrap()
+        .iter()
+        .map(|subst: &Substitution| subst.get(name).unwrap().clone())
+        .collect()
+}
+
+// same as `answers`, for evaluations that find them in no fixed order
+#[cfg(test)]
+pub fn sorted_answers(engine: &mut Engine, goal: &str, name: &str) -> Vec<Token> {
+    let mut res = answers(engine, goal, name);
+    res.sort_by_key(|token| token.to_string());
+
+    res
+}
+
+#[cfg(test)]
+pub fn atoms(names: &[&str]) -> Vec<Token> {
+    names
+        .iter()
+        .map(|name| Token::Atom(name.to_string()))
+        .collect()
+}
@@ -3,6 +3,16 @@ pub struct ArrayMap<Key, Val> {
     store: Vec<(Key, Val)>,
 }
 
+impl<Key, Val> Default for ArrayMap<Key, Val>
+where
+    Key: PartialEq + core::fmt::Debug,
+    Val: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<Key, Val> ArrayMap<Key, Val>
 where
     Key: PartialEq + core::fmt::Debug,
@@ -35,6 +45,10 @@ where
         res
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = &(Key, Val)> {
+        self.store.iter()
+    }
+
     pub fn push(&mut self, key: Key, val: Val) {
         self.store.push((key, val))
     }
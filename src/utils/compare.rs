@@ -0,0 +1,44 @@
+use crate::grammar::token::Token;
+use std::cmp::Ordering;
+
+// ordering of two values of the same kind, lists are compared element by
+// element, values of different kinds are not ordered, except integers and
+// floats which are both numbers
+pub fn compare(a: &Token, b: &Token) -> Option<Ordering> {
+    match (a.inner(), b.inner()) {
+        (Token::Number(a), Token::Number(b)) => a.partial_cmp(b),
+        (Token::Integer(a), Token::Integer(b)) => Some(a.cmp(b)),
+        (Token::Integer(a), Token::Number(b)) => (*a as f64).partial_cmp(b),
+        (Token::Number(a), Token::Integer(b)) => a.partial_cmp(&(*b as f64)),
+        (Token::String(a), Token::String(b)) | (Token::Atom(a), Token::Atom(b)) => Some(a.cmp(b)),
+        (Token::Boolean(a), Token::Boolean(b)) => Some(a.cmp(b)),
+        (Token::List(a), Token::List(b)) => {
+            for (x, y) in a.iter().zip(b.iter()) {
+                match compare(x, y)? {
+                    Ordering::Equal => continue,
+                    ordering => return Some(ordering),
+                }
+            }
+
+            Some(a.len().cmp(&b.len()))
+        }
+        _ => None,
+    }
+}
+
+// structural equality, unlike `Token::eq` a variable only equals itself
+pub fn equal(a: &Token, b: &Token) -> bool {
+    match (a.inner(), b.inner()) {
+        (Token::List(a), Token::List(b)) => {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(x, y)| equal(x, y))
+        }
+        (Token::KeywordArg(a, x), Token::KeywordArg(b, y)) => a == b && equal(x, y),
+        (Token::Closure(a), Token::Closure(b)) => std::rc::Rc::ptr_eq(a, b),
+        (Token::Variable(a, _), Token::Variable(b, _))
+        | (Token::Wildcard(a, _), Token::Wildcard(b, _))
+        | (Token::Identifier(a), Token::Identifier(b))
+        | (Token::Operator(a), Token::Operator(b))
+        | (Token::Keyword(a), Token::Keyword(b)) => a == b,
+        (a, b) => compare(a, b) == Some(Ordering::Equal),
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+use super::compare::{compare, equal};
+#[cfg(test)]
+use crate::grammar::token::Token;
+#[cfg(test)]
+use std::cmp::Ordering;
+
+#[test]
+fn should_order_values_of_same_kind() {
+    let cases = vec![
+        (Token::Number(1.0), Token::Number(2.0), Ordering::Less),
+        (
+            Token::String("b".to_owned()),
+            Token::String("a".to_owned()),
+            Ordering::Greater,
+        ),
+        (Token::_false(), Token::_true(), Ordering::Less),
+        (
+            Token::Atom("abc".to_owned()),
+            Token::Atom("abc".to_owned()),
+            Ordering::Equal,
+        ),
+    ];
+
+    for (a, b, expected) in cases {
+        assert_eq!(Some(expected), compare(&a, &b));
+    }
+}
+
+#[test]
+fn should_order_lists_element_by_element() {
+    let short = Token::List(vec![Token::Number(1.0), Token::Number(2.0)]);
+    let long = Token::List(vec![
+        Token::Number(1.0),
+        Token::Number(2.0),
+        Token::Number(0.0),
+    ]);
+    let big = Token::List(vec![Token::Number(1.0), Token::Number(3.0)]);
+
+    assert_eq!(Some(Ordering::Less), compare(&short, &long));
+    assert_eq!(Some(Ordering::Greater), compare(&big, &long));
+}
+
+#[test]
+fn should_not_order_different_kinds() {
+    let res = compare(&Token::Number(1.0), &Token::String("1".to_owned()));
+
+    assert_eq!(None, res)
+}
+
+#[test]
+fn should_not_equal_unbound_variables() {
+    let var = Token::Variable("X".to_owned(), None);
+
+    assert!(equal(&var, &var.clone()));
+    assert!(!equal(&var, &Token::Number(1.0)));
+}
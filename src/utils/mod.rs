@@ -1,5 +1,10 @@
 pub mod arraymap;
+pub mod compare;
+pub mod compare_tests;
 pub mod operation;
 pub mod posibility;
 pub mod trie;
+pub mod trie_tests;
+pub mod unify;
+pub mod unify_tests;
 pub mod variables;
@@ -1,9 +1,13 @@
+use crate::error::LexError;
 use crate::grammar::token::Token;
 use crate::state::State;
 
-type BinaryOpCallback<T> = Box<dyn Fn(&mut State, (T, T)) -> Option<Token>>;
+type BinaryOpCallback<T> = Box<dyn Fn(&mut State, (T, T)) -> Result<Token, LexError>>;
 
 pub struct BinaryOperation {
+    // integers are given to `number_fn` as floats when there is no
+    // `integer_fn`, and when mixed with a float
+    integer_fn: Option<BinaryOpCallback<i64>>,
     number_fn: Option<BinaryOpCallback<f64>>,
     boolean_fn: Option<BinaryOpCallback<bool>>,
     atom_fn: Option<BinaryOpCallback<String>>,
@@ -12,9 +16,16 @@ pub struct BinaryOperation {
     any_fn: Option<BinaryOpCallback<Token>>,
 }
 
+impl Default for BinaryOperation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl BinaryOperation {
     pub fn new() -> Self {
         BinaryOperation {
+            integer_fn: None,
             number_fn: None,
             boolean_fn: None,
             atom_fn: None,
@@ -24,6 +35,12 @@ impl BinaryOperation {
         }
     }
 
+    pub fn for_integer(&mut self, func: BinaryOpCallback<i64>) -> &mut Self {
+        self.integer_fn = Some(func);
+
+        self
+    }
+
     pub fn for_number(&mut self, func: BinaryOpCallback<f64>) -> &mut Self {
         self.number_fn = Some(func);
 
@@ -60,65 +77,98 @@ impl BinaryOperation {
         self
     }
 
-    pub fn exec(&mut self, state: &mut State, arg: Token) -> Option<Token> {
-        if let Token::List(lst) = arg {
-            match &lst[..] {
-                // [op, Token::Variable(_, Some(a)), Token::Variable(_, Some(b))] => {
-                //     return self.exec(state, Token::List(vec![op.clone(), *a.clone(), *b.clone()]))
-                // }
-                [_, x, y] => {
-                    if let Some(func) = &self.any_fn {
-                        return func(state, (x.clone(), y.clone()));
-                    }
+    fn expected(&self) -> String {
+        expected_types(&[
+            (
+                "integer",
+                self.integer_fn.is_some() && self.number_fn.is_none(),
+            ),
+            ("number", self.number_fn.is_some()),
+            ("boolean", self.boolean_fn.is_some()),
+            ("atom", self.atom_fn.is_some()),
+            ("string", self.string_fn.is_some()),
+            ("list", self.list_fn.is_some()),
+        ])
+    }
+
+    pub fn exec(&mut self, state: &mut State, arg: Token) -> Result<Token, LexError> {
+        if let Token::List(lst) = arg.clone() {
+            if let [_, x, y] = &lst[..] {
+                if let Some(func) = &self.any_fn {
+                    return func(state, (x.clone(), y.clone()));
+                }
 
-                    let x = state.exec(x.clone());
-                    let y = state.exec(y.clone());
+                let x = state.exec(x.clone())?;
+                let y = state.exec(y.clone())?;
 
-                    match (x, y) {
-                        (Token::Number(a), Token::Number(b)) => {
-                            if let Some(func) = &self.number_fn {
-                                return func(state, (a, b));
-                            }
+                match (x.clone(), y.clone()) {
+                    (Token::Integer(a), Token::Integer(b)) => {
+                        if let Some(func) = &self.integer_fn {
+                            return func(state, (a, b));
                         }
-                        (Token::Boolean(a), Token::Boolean(b)) => {
-                            if let Some(func) = &self.boolean_fn {
-                                return func(state, (a, b));
-                            }
+                        if let Some(func) = &self.number_fn {
+                            return func(state, (a as f64, b as f64));
                         }
-                        (Token::Atom(a), Token::Atom(b)) => {
-                            if let Some(func) = &self.atom_fn {
-                                return func(state, (a, b));
-                            }
+                    }
+                    (Token::Number(a), Token::Number(b)) => {
+                        if let Some(func) = &self.number_fn {
+                            return func(state, (a, b));
                         }
-                        (Token::String(a), Token::String(b)) => {
-                            if let Some(func) = &self.string_fn {
-                                return func(state, (a, b));
-                            }
+                    }
+                    (Token::Integer(a), Token::Number(b)) => {
+                        if let Some(func) = &self.number_fn {
+                            return func(state, (a as f64, b));
                         }
-                        (Token::List(a), Token::List(b)) => {
-                            if let Some(func) = &self.list_fn {
-                                return func(state, (a, b));
-                            }
+                    }
+                    (Token::Number(a), Token::Integer(b)) => {
+                        if let Some(func) = &self.number_fn {
+                            return func(state, (a, b as f64));
                         }
-                        (a, b) => {
-                            if let Some(func) = &self.any_fn {
-                                return func(state, (a, b));
-                            }
+                    }
+                    (Token::Boolean(a), Token::Boolean(b)) => {
+                        if let Some(func) = &self.boolean_fn {
+                            return func(state, (a, b));
                         }
-                    };
+                    }
+                    (Token::Atom(a), Token::Atom(b)) => {
+                        if let Some(func) = &self.atom_fn {
+                            return func(state, (a, b));
+                        }
+                    }
+                    (Token::String(a), Token::String(b)) => {
+                        if let Some(func) = &self.string_fn {
+                            return func(state, (a, b));
+                        }
+                    }
+                    (Token::List(a), Token::List(b)) => {
+                        if let Some(func) = &self.list_fn {
+                            return func(state, (a, b));
+                        }
+                    }
+                    (a, b) => {
+                        if let Some(func) = &self.any_fn {
+                            return func(state, (a, b));
+                        }
+                    }
+                };
 
-                    return None;
-                }
-                _ => (),
+                return Err(LexError::TypeMismatch {
+                    expected: self.expected(),
+                    found: Token::List(vec![x, y]),
+                });
             }
         }
-        None
+
+        Err(arity_error(2, arg))
     }
 }
 
-type UnaryOpCallback<T> = Box<dyn Fn(&mut State, T) -> Option<Token>>;
+type UnaryOpCallback<T> = Box<dyn Fn(&mut State, T) -> Result<Token, LexError>>;
 
 pub struct UnaryOperation {
+    // integers are given to `number_fn` as floats when there is no
+    // `integer_fn`
+    integer_fn: Option<UnaryOpCallback<i64>>,
     number_fn: Option<UnaryOpCallback<f64>>,
     boolean_fn: Option<UnaryOpCallback<bool>>,
     atom_fn: Option<UnaryOpCallback<String>>,
@@ -128,9 +178,16 @@ pub struct UnaryOperation {
     executed_any_fn: Option<UnaryOpCallback<Token>>,
 }
 
+impl Default for UnaryOperation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl UnaryOperation {
     pub fn new() -> Self {
         UnaryOperation {
+            integer_fn: None,
             number_fn: None,
             boolean_fn: None,
             atom_fn: None,
@@ -141,6 +198,12 @@ impl UnaryOperation {
         }
     }
 
+    pub fn for_integer(&mut self, func: UnaryOpCallback<i64>) -> &mut Self {
+        self.integer_fn = Some(func);
+
+        self
+    }
+
     pub fn for_number(&mut self, func: UnaryOpCallback<f64>) -> &mut Self {
         self.number_fn = Some(func);
 
@@ -183,62 +246,105 @@ impl UnaryOperation {
         self
     }
 
-    pub fn exec(&self, state: &mut State, arg: Token) -> Option<Token> {
-        if let Token::List(lst) = arg {
-            match &lst[..] {
-                // [op, Token::Variable(_, Some(x))] => {
-                //     x.values().iter().map
-                //     return self.exec(state, Token::List(vec![op.clone(), x.clone()]))
-                // }
-                [_, x] => {
-                    let inp = state.exec(x.clone());
+    fn expected(&self) -> String {
+        expected_types(&[
+            (
+                "integer",
+                self.integer_fn.is_some() && self.number_fn.is_none(),
+            ),
+            ("number", self.number_fn.is_some()),
+            ("boolean", self.boolean_fn.is_some()),
+            ("atom", self.atom_fn.is_some()),
+            ("string", self.string_fn.is_some()),
+            ("list", self.list_fn.is_some()),
+        ])
+    }
 
-                    if let Some(func) = &self.any_fn {
-                        return func(state, x.clone());
-                    }
+    pub fn exec(&self, state: &mut State, arg: Token) -> Result<Token, LexError> {
+        if let Token::List(lst) = arg.clone() {
+            if let [_, x] = &lst[..] {
+                // `for_any` receives its operand unevaluated
+                if let Some(func) = &self.any_fn {
+                    return func(state, x.clone());
+                }
 
-                    if let Some(func) = &self.executed_any_fn {
-                        return func(state, inp);
-                    }
+                let inp = state.exec(x.clone())?;
+
+                if let Some(func) = &self.executed_any_fn {
+                    return func(state, inp);
+                }
 
-                    match inp {
-                        Token::Number(a) => {
-                            if let Some(func) = &self.number_fn {
-                                return func(state, a);
-                            }
+                match inp.clone() {
+                    Token::Integer(a) => {
+                        if let Some(func) = &self.integer_fn {
+                            return func(state, a);
+                        }
+                        if let Some(func) = &self.number_fn {
+                            return func(state, a as f64);
                         }
-                        Token::Boolean(a) => {
-                            if let Some(func) = &self.boolean_fn {
-                                return func(state, a);
-                            }
+                    }
+                    Token::Number(a) => {
+                        if let Some(func) = &self.number_fn {
+                            return func(state, a);
+                        }
+                    }
+                    Token::Boolean(a) => {
+                        if let Some(func) = &self.boolean_fn {
+                            return func(state, a);
                         }
-                        Token::Atom(a) => {
-                            if let Some(func) = &self.atom_fn {
-                                return func(state, a);
-                            }
+                    }
+                    Token::Atom(a) => {
+                        if let Some(func) = &self.atom_fn {
+                            return func(state, a);
                         }
-                        Token::String(a) => {
-                            if let Some(func) = &self.string_fn {
-                                return func(state, a);
-                            }
+                    }
+                    Token::String(a) => {
+                        if let Some(func) = &self.string_fn {
+                            return func(state, a);
                         }
-                        Token::List(a) => {
-                            if let Some(func) = &self.list_fn {
-                                return func(state, a);
-                            }
+                    }
+                    Token::List(a) => {
+                        if let Some(func) = &self.list_fn {
+                            return func(state, a);
                         }
-                        a => {
-                            if let Some(func) = &self.any_fn {
-                                return func(state, a);
-                            }
+                    }
+                    a => {
+                        if let Some(func) = &self.any_fn {
+                            return func(state, a);
                         }
-                    };
+                    }
+                };
 
-                    return None;
-                }
-                _ => (),
+                return Err(LexError::TypeMismatch {
+                    expected: self.expected(),
+                    found: inp,
+                });
             }
         }
-        None
+
+        Err(arity_error(1, arg))
+    }
+}
+
+fn expected_types(types: &[(&str, bool)]) -> String {
+    let names: Vec<&str> = types
+        .iter()
+        .filter(|(_, handled)| *handled)
+        .map(|(name, _)| *name)
+        .collect();
+
+    names.join(" or ")
+}
+
+fn arity_error(expected: usize, call: Token) -> LexError {
+    let found = match &call {
+        Token::List(lst) => lst.len().saturating_sub(1),
+        _ => 0,
+    };
+
+    LexError::Arity {
+        expected,
+        found,
+        call,
     }
 }
@@ -3,6 +3,15 @@ pub struct Posibility<T> {
     store: Vec<T>,
 }
 
+impl<T> Default for Posibility<T>
+where
+    T: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Posibility<T>
 where
     T: Clone,
@@ -35,7 +44,7 @@ where
                 }
             }
         }
-        return false;
+        false
     }
 }
 
@@ -50,6 +59,6 @@ where
             }
         }
 
-        return false;
+        false
     }
 }
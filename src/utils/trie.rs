@@ -1,187 +1,186 @@
 use crate::definition::Definition;
 use crate::grammar::token::Token;
-
-#[derive(Debug, Clone)]
-pub struct Node {
-    value: Option<Token>,
-    pub data: Vec<Definition>,
-    pub children: Vec<Box<Node>>,
+use std::collections::HashMap;
+
+// hashable part of a token used to walk the trie, nested lists are only
+// keyed by their length so every signature maps to a short path
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Key {
+    String(String),
+    Number(u64),
+    Integer(i64),
+    Boolean(bool),
+    Atom(String),
+    Operator(String),
+    Keyword(String),
+    Identifier(String),
+    KeywordArg(String),
+    List(usize),
+    Document,
+    Definition,
+    // variables, wildcards and values match any token
+    Any,
 }
 
-impl Node {
-    pub fn new() -> Self {
-        Self {
-            value: None,
-            data: vec![],
-            children: vec![],
+impl Key {
+    pub fn of(token: &Token) -> Self {
+        match token.inner() {
+            Token::String(s) => Key::String(s.clone()),
+            // integral floats equal the integer they hold, e.g. 2.0 and 2,
+            // -0.0 and 0.0 included, so they must share its key
+            Token::Number(n) if n.fract() == 0.0 && n.abs() < i64::MAX as f64 => {
+                Key::Integer(*n as i64)
+            }
+            Token::Number(n) => Key::Number(n.to_bits()),
+            Token::Integer(n) => Key::Integer(*n),
+            Token::Boolean(b) => Key::Boolean(*b),
+            Token::Atom(s) => Key::Atom(s.clone()),
+            Token::Operator(s) => Key::Operator(s.clone()),
+            Token::Keyword(s) => Key::Keyword(s.clone()),
+            Token::Identifier(s) => Key::Identifier(s.clone()),
+            Token::KeywordArg(s, _) => Key::KeywordArg(s.clone()),
+            Token::List(list) => Key::List(list.len()),
+            Token::Document(_, _) => Key::Document,
+            Token::Definition(_, _) => Key::Definition,
+            _ => Key::Any,
         }
     }
 
-    pub fn with_value(&self, value: Token) -> Self {
-        let mut res = self.clone();
-
-        res.value = Some(value);
-        res
-    }
-
-    pub fn with_data(&self, data: Definition) -> Self {
-        let mut res = self.clone();
+    // a list is keyed by its length followed by each of its elements
+    pub fn path(token: &Token) -> Vec<Key> {
+        let mut path = vec![Key::of(token)];
+        if let Token::List(list) = token.inner() {
+            path.extend(list.iter().map(Key::of));
+        }
 
-        res.data.push(data);
-        res
+        path
     }
+}
 
-    pub fn push(&mut self, nodes: Vec<Node>) {
-        if nodes.len() < 1 {
-            return;
-        }
-
-        let mut head = nodes.first().unwrap().clone();
-        let tail = nodes[1..].to_vec();
+// insertion sequence, signature and definition
+type Entry = (usize, Token, Definition);
 
-        for (i, child) in self.children.iter().enumerate() {
-            if child.value != None && child.value.clone().unwrap() == head.clone().value.unwrap() {
-                self.children[i].push(tail);
-                return;
-            }
-        }
+#[derive(Debug, Clone, Default)]
+pub struct Node {
+    pub data: Vec<Entry>,
+    pub children: HashMap<Key, Node>,
+    pub any: Option<Box<Node>>,
+}
 
-        head.push(tail);
-        self.children.push(Box::new(head.clone()));
+impl Node {
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    pub fn find_all(&self, list: Vec<Token>) -> Vec<Node> {
-        if list.len() < 1 {
-            return vec![];
+    pub fn push(&mut self, path: &[Key], data: Entry) {
+        match path.split_first() {
+            None => self.data.push(data),
+            Some((Key::Any, tail)) => self
+                .any
+                .get_or_insert_with(|| Box::new(Node::new()))
+                .push(tail, data),
+            Some((key, tail)) => self
+                .children
+                .entry(key.clone())
+                .or_default()
+                .push(tail, data),
         }
+    }
 
-        let mut res = vec![];
-
-        let head = list.first().unwrap().clone();
-
-        for child in self.children.iter() {
-            if child.value != None && child.value.clone().unwrap() == head.clone() {
-                if list.len() > 1 {
-                    let tail = list[1..].to_vec();
-                    res.extend(child.find_all(tail))
-                } else {
-                    res.push(*child.clone())
+    pub fn find_all(&self, path: &[Key], res: &mut Vec<Entry>) {
+        let (head, tail) = match path.split_first() {
+            None => return res.extend(self.data.iter().cloned()),
+            Some(split) => split,
+        };
+
+        match head {
+            // an unbound variable in the call can match any branch
+            Key::Any => {
+                for child in self.children.values() {
+                    child.find_all(tail, res);
                 }
             }
-        }
-
-        res
-    }
-
-    pub fn find(&self, list: Vec<Token>) -> Option<Node> {
-        if list.len() < 1 {
-            return None;
-        }
-
-        let head = list.first().unwrap().clone();
-        for child in self.children.iter() {
-            if child.value != None && child.value.clone().unwrap() == head.clone() {
-                if list.len() > 1 {
-                    let tail = list[1..].to_vec();
-                    match child.find(tail) {
-                        None => (),
-                        res => return res,
-                    }
-                } else {
-                    return Some(*child.clone());
+            key => {
+                if let Some(child) = self.children.get(key) {
+                    child.find_all(tail, res);
                 }
             }
         }
 
-        return None;
-    }
-
-    pub fn is_final(&self) -> bool {
-        self.children.len() == 0
+        if let Some(any) = &self.any {
+            any.find_all(tail, res);
+        }
     }
 
-    pub fn keys(&self) -> Vec<Vec<Token>> {
-        if self.is_final() {
-            return vec![vec![self.clone().value.unwrap()]];
+    pub fn values(&self, res: &mut Vec<Entry>) {
+        res.extend(self.data.iter().cloned());
+        for child in self.children.values() {
+            child.values(res);
         }
-
-        // if root
-        if self.value == None {
-            return self
-                .children
-                .clone()
-                .into_iter()
-                .map(|child| child.keys())
-                .flatten()
-                .collect();
+        if let Some(any) = &self.any {
+            any.values(res);
         }
-
-        let res: Vec<Vec<Token>> = self
-            .children
-            .clone()
-            .into_iter()
-            .map(move |node| {
-                let prefix = self.clone().value.unwrap();
-                let keys: Vec<Vec<Token>> = node
-                    .keys()
-                    .into_iter()
-                    .map(move |key| {
-                        let mut res: Vec<Token> = vec![prefix.clone()];
-                        res.extend(key);
-
-                        res
-                    })
-                    .collect();
-
-                keys
-            })
-            .flatten()
-            .collect();
-
-        res
     }
 }
 
-#[derive(Debug, Clone)]
+// definitions indexed by the path of their input signature, lookup only
+// visits the branches a call can match instead of every definition
+#[derive(Debug, Clone, Default)]
 pub struct Trie {
     root: Node,
+    // signatures matching any token at all, e.g. `Token::Value`
+    wild: Vec<Entry>,
+    len: usize,
 }
 
 impl Trie {
     pub fn new() -> Self {
-        Self { root: Node::new() }
+        Self::default()
     }
 
-    pub fn push(&mut self, list: Vec<Token>, data: Definition) {
-        let found = self.find(list.clone());
-        let nodes: Vec<Node> = list
-            .clone()
-            .into_iter()
-            .enumerate()
-            .map(move |(i, item)| {
-                let mut node = Node::new().with_value(item);
-                if i == list.len() - 1 {
-                    if let Some(f) = &found {
-                        node = f.with_data(data.clone());
-                    } else {
-                        node = node.with_data(data.clone())
-                    }
-                }
-                node
-            })
-            .collect();
-        self.root.push(nodes);
+    pub fn len(&self) -> usize {
+        self.len
     }
 
-    pub fn find(&self, list: Vec<Token>) -> Option<Node> {
-        self.root.find(list)
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push(&mut self, key: Token, data: Definition) {
+        let path = Key::path(&key);
+        let entry = (self.len, key, data);
+        self.len += 1;
+
+        match path[..] {
+            [Key::Any] => self.wild.push(entry),
+            ref path => self.root.push(path, entry),
+        }
     }
 
-    pub fn find_all(&self, list: Vec<Token>) -> Vec<Node> {
-        self.root.find_all(list)
+    // definitions whose signature matches `token`, in the order they were
+    // pushed, the trie only narrows the candidates down to the ones sharing
+    // its path, each is still checked against the full signature
+    pub fn search(&self, token: &Token) -> Vec<Definition> {
+        let mut res = self.wild.clone();
+
+        match Key::path(token)[..] {
+            // an unbound variable as the whole call matches everything
+            [Key::Any] => self.root.values(&mut res),
+            ref path => self.root.find_all(path, &mut res),
+        }
+
+        res.sort_by_key(|(i, _, _)| *i);
+        res.into_iter()
+            .filter(|(_, key, _)| key == token)
+            .map(|(_, _, def)| def)
+            .collect()
     }
 
-    pub fn keys(&self) -> Vec<Vec<Token>> {
-        self.root.keys()
+    pub fn iter(&self) -> impl Iterator<Item = (Token, Definition)> {
+        let mut res = self.wild.clone();
+        self.root.values(&mut res);
+
+        res.sort_by_key(|(i, _, _)| *i);
+        res.into_iter().map(|(_, key, def)| (key, def))
     }
 }
@@ -0,0 +1,124 @@
+#[cfg(test)]
+use super::trie::Trie;
+#[cfg(test)]
+use crate::{definition::Definition, grammar::token::Token, state::State};
+#[cfg(test)]
+use std::rc::Rc;
+
+#[cfg(test)]
+fn fact(sig: Token, n: f64) -> Definition {
+    Definition {
+        inp_sig: sig,
+        out_sig: Token::Value,
+        res_sig: Token::Value,
+        func: Rc::new(Box::new(move |_: &mut State, _: Token| {
+            Ok(Token::Number(n))
+        })),
+    }
+}
+
+#[cfg(test)]
+fn edge(from: &str, to: Token) -> Token {
+    Token::List(vec![
+        Token::Atom(from.to_owned()),
+        Token::Identifier("to".to_owned()),
+        to,
+    ])
+}
+
+#[cfg(test)]
+fn results(trie: &Trie, call: Token) -> Vec<Token> {
+    let mut state = State::new();
+    trie.search(&call)
+        .into_iter()
+        .map(|def| def.handle(&mut state, call.clone()).unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+fn atom(name: &str) -> Token {
+    Token::Atom(name.to_owned())
+}
+
+#[test]
+fn should_find_exact_match() {
+    let mut trie = Trie::new();
+    for (i, to) in ["b", "c", "d"].iter().enumerate() {
+        let sig = edge("a", atom(to));
+        trie.push(sig.clone(), fact(sig, i as f64));
+    }
+
+    assert_eq!(trie.len(), 3);
+    assert_eq!(
+        results(&trie, edge("a", atom("c"))),
+        vec![Token::Number(1.0)]
+    );
+    assert_eq!(results(&trie, edge("b", atom("c"))), vec![]);
+}
+
+#[test]
+fn should_follow_variable_branches_in_order() {
+    let mut trie = Trie::new();
+    let sigs = vec![
+        edge("a", atom("b")),
+        edge("a", Token::Variable("X".to_owned(), None)),
+        Token::Value,
+        edge("a", atom("c")),
+    ];
+    for (i, sig) in sigs.into_iter().enumerate() {
+        trie.push(sig.clone(), fact(sig, i as f64));
+    }
+
+    assert_eq!(
+        results(&trie, edge("a", atom("c"))),
+        vec![Token::Number(1.0), Token::Number(2.0), Token::Number(3.0)]
+    );
+    assert_eq!(
+        results(&trie, edge("a", Token::Wildcard("Y".to_owned(), None))),
+        vec![
+            Token::Number(0.0),
+            Token::Number(1.0),
+            Token::Number(2.0),
+            Token::Number(3.0)
+        ]
+    );
+}
+
+#[test]
+fn should_not_match_lists_of_different_length() {
+    let mut trie = Trie::new();
+    let sig = Token::List(vec![Token::Identifier("f".to_owned()), Token::Value]);
+    trie.push(sig.clone(), fact(sig, 0.0));
+
+    let call = Token::List(vec![
+        Token::Identifier("f".to_owned()),
+        Token::Number(1.0),
+        Token::Number(2.0),
+    ]);
+    assert_eq!(results(&trie, call), vec![]);
+
+    let call = Token::List(vec![
+        Token::Identifier("f".to_owned()),
+        Token::List(vec![Token::Number(1.0), Token::Number(2.0)]),
+    ]);
+    assert_eq!(results(&trie, call), vec![Token::Number(0.0)]);
+}
+
+#[test]
+fn should_share_keys_of_integral_floats_and_integers() {
+    let mut trie = Trie::new();
+    let sig = edge("a", Token::Integer(1));
+    trie.push(sig.clone(), fact(sig, 0.0));
+    let sig = edge("a", Token::Number(2.0));
+    trie.push(sig.clone(), fact(sig, 1.0));
+
+    assert_eq!(
+        results(&trie, edge("a", Token::Number(1.0))),
+        vec![Token::Number(0.0)]
+    );
+    assert_eq!(
+        results(&trie, edge("a", Token::Integer(2))),
+        vec![Token::Number(1.0)]
+    );
+    assert_eq!(results(&trie, edge("a", Token::Number(1.5))), vec![]);
+}
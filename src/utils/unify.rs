@@ -0,0 +1,241 @@
+use crate::grammar::token::Token;
+use crate::utils::compare;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// counter used to rename the variables of a pattern apart from the ones of
+// the value it is unified with
+static FRESH: AtomicUsize = AtomicUsize::new(0);
+
+// name a variable or wildcard is bound under, wildcards get their own
+// namespace so `X` and `_X` are different variables
+pub fn key(token: &Token) -> Option<String> {
+    match token.inner() {
+        Token::Variable(name, None) => Some(name.clone()),
+        Token::Wildcard(name, None) => Some(format!("_{}", name)),
+        _ => None,
+    }
+}
+
+// names of every variable in `token`, in order of appearance
+pub fn variables(token: &Token) -> Vec<String> {
+    let mut res = vec![];
+    collect(token, &mut res);
+
+    res
+}
+
+fn collect(token: &Token, res: &mut Vec<String>) {
+    if let Some(name) = key(token) {
+        if !res.contains(&name) {
+            res.push(name);
+        }
+        return;
+    }
+
+    match token.inner() {
+        Token::List(list) => list.iter().for_each(|token| collect(token, res)),
+        Token::Document(name, content) => {
+            collect(name, res);
+            collect(content, res);
+        }
+        Token::Definition(a, b) => {
+            collect(a, res);
+            collect(b, res);
+        }
+        Token::KeywordArg(_, value) => collect(value, res),
+        _ => (),
+    }
+}
+
+// suffix no variable produced by the parser can contain
+pub fn fresh() -> String {
+    format!("#{}", FRESH.fetch_add(1, Ordering::Relaxed))
+}
+
+// append `suffix` to the name of every variable in `token`
+pub fn rename(token: Token, suffix: &str) -> Token {
+    match token {
+        Token::Variable(name, None) => Token::Variable(name + suffix, None),
+        Token::Wildcard(name, None) => Token::Wildcard(name + suffix, None),
+        token => map(token, &|token| rename(token, suffix)),
+    }
+}
+
+// apply `f` to the direct children of `token`, keeping its span
+fn map(token: Token, f: &dyn Fn(Token) -> Token) -> Token {
+    match token {
+        Token::Spanned(span, token) => Token::Spanned(span, Box::new(f(*token))),
+        Token::List(list) => Token::List(list.into_iter().map(f).collect()),
+        Token::Document(name, content) => {
+            Token::Document(Box::new(f(*name)), Box::new(f(*content)))
+        }
+        Token::Definition(a, b) => Token::Definition(Box::new(f(*a)), Box::new(f(*b))),
+        Token::KeywordArg(name, value) => Token::KeywordArg(name, Box::new(f(*value))),
+        token => token,
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Substitution {
+    bindings: HashMap<String, Token>,
+}
+
+impl Substitution {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Token> {
+        self.bindings.get(name)
+    }
+
+    pub fn bind(&mut self, name: &str, value: Token) {
+        self.bindings.insert(name.to_owned(), value);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.bindings.len()
+    }
+
+    // follow variable bindings until reaching an unbound variable or a value
+    pub fn walk<'a>(&'a self, token: &'a Token) -> &'a Token {
+        let mut token = token.inner();
+        while let Some(value) = key(token).and_then(|name| self.bindings.get(&name)) {
+            token = value.inner();
+        }
+
+        token
+    }
+
+    // replace every bound variable in `token`, looking through spans
+    pub fn resolve(&self, token: Token) -> Token {
+        match key(&token).and_then(|name| self.bindings.get(&name)) {
+            Some(value) => self.resolve(value.clone()),
+            None => map(token, &|token| self.resolve(token)),
+        }
+    }
+
+    fn occurs(&self, name: &str, token: &Token) -> bool {
+        let token = self.walk(token);
+        if key(token).as_deref() == Some(name) {
+            return true;
+        }
+
+        match token {
+            Token::List(list) => list.iter().any(|token| self.occurs(name, token)),
+            Token::Document(_, content) => self.occurs(name, content),
+            Token::Definition(a, b) => self.occurs(name, a) || self.occurs(name, b),
+            Token::KeywordArg(_, value) => self.occurs(name, value),
+            _ => false,
+        }
+    }
+
+    // extend the substitution so that `a` and `b` become equal, on failure
+    // the substitution is left partially extended and should be discarded
+    pub fn unify(&mut self, a: &Token, b: &Token) -> bool {
+        let a = self.walk(a).clone();
+        let b = self.walk(b).clone();
+
+        match (&a, &b) {
+            (Token::Value, _) | (_, Token::Value) => true,
+            // a variable bound by an earlier pass over the token
+            (Token::Variable(_, Some(value)), other) | (other, Token::Variable(_, Some(value))) => {
+                match value.values().first() {
+                    Some(value) => self.unify(value, other),
+                    None => false,
+                }
+            }
+            _ => match (key(&a), key(&b)) {
+                (Some(x), Some(y)) if x == y => true,
+                // a positional variable never takes a keyword argument
+                (Some(_), None) if matches!(b, Token::KeywordArg(_, _)) => false,
+                (None, Some(_)) if matches!(a, Token::KeywordArg(_, _)) => false,
+                (Some(x), _) => self.assign(&x, b),
+                (_, Some(y)) => self.assign(&y, a),
+                _ => self.unify_values(&a, &b),
+            },
+        }
+    }
+
+    fn assign(&mut self, name: &str, value: Token) -> bool {
+        if self.occurs(name, &value) {
+            return false;
+        }
+
+        self.bind(name, value);
+        true
+    }
+
+    fn unify_values(&mut self, a: &Token, b: &Token) -> bool {
+        match (a, b) {
+            (Token::List(a), Token::List(b)) => {
+                a.len() == b.len() && a.iter().zip(b.iter()).all(|(x, y)| self.unify(x, y))
+            }
+            // same parts `Token::eq` compares
+            (Token::Document(_, a), Token::Document(_, b)) => self.unify(a, b),
+            (Token::Definition(a, _), Token::Definition(b, _)) => self.unify(a, b),
+            (Token::KeywordArg(a, x), Token::KeywordArg(b, y)) => a == b && self.unify(x, y),
+            // variadic signature, operands past its own are left unbound
+            (Token::ListOperation(head, args), Token::List(list))
+            | (Token::List(list), Token::ListOperation(head, args)) => {
+                list.len() > args.len()
+                    && self.unify(head, &list[0])
+                    && args
+                        .iter()
+                        .zip(list[1..].iter())
+                        .all(|(x, y)| self.unify(x, y))
+            }
+            (Token::List(_), _) | (_, Token::List(_)) => false,
+            (Token::KeywordArg(_, _), _) | (_, Token::KeywordArg(_, _)) => false,
+            // numbers are equal by value as with `=` outside of queries
+            (Token::Integer(_), Token::Number(_)) | (Token::Number(_), Token::Integer(_)) => {
+                compare::equal(a, b)
+            }
+            (a, b) => a == b,
+        }
+    }
+}
+
+// operands of `(= a b)`, which inside queries unifies them
+pub fn equation(token: &Token) -> Option<(&Token, &Token)> {
+    match token.inner() {
+        Token::List(list) => match &list[..] {
+            [op, a, b] if matches!(op.inner(), Token::Operator(op) if op == "=") => Some((a, b)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// unify `pattern` with `value` keeping their variables apart, returns the
+// bindings of the variables of `pattern`
+pub fn matches(pattern: &Token, value: &Token) -> Option<Substitution> {
+    let suffix = fresh();
+    let renamed = rename(pattern.clone(), &suffix);
+
+    let mut subst = Substitution::new();
+    if !subst.unify(&renamed, value) {
+        return None;
+    }
+
+    let mut res = Substitution::new();
+    for name in variables(pattern) {
+        let value = subst.resolve(rename(variable(&name), &suffix));
+        res.bind(&name, value);
+    }
+
+    Some(res)
+}
+
+// variable token bound under `name`
+pub fn variable(name: &str) -> Token {
+    match name.strip_prefix('_') {
+        Some(name) => Token::Wildcard(name.to_owned(), None),
+        None => Token::Variable(name.to_owned(), None),
+    }
+}
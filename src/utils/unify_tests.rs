@@ -0,0 +1,88 @@
+#[cfg(test)]
+use super::unify::{matches, Substitution};
+#[cfg(test)]
+use crate::grammar::token::Token;
+
+#[cfg(test)]
+fn var(name: &str) -> Token {
+    Token::Variable(name.to_owned(), None)
+}
+
+#[cfg(test)]
+fn atom(name: &str) -> Token {
+    Token::Atom(name.to_owned())
+}
+
+#[cfg(test)]
+fn same(a: Token, b: Token) -> Token {
+    Token::List(vec![a, Token::Identifier("same".to_owned()), b])
+}
+
+#[test]
+fn should_bind_repeated_variables_consistently() {
+    let pattern = same(var("X"), var("X"));
+
+    assert!(matches(&pattern, &same(atom("a"), atom("a"))).is_some());
+    assert!(matches(&pattern, &same(atom("a"), atom("b"))).is_none());
+}
+
+#[test]
+fn should_return_bindings_of_the_pattern() {
+    let pattern = Token::List(vec![Token::Identifier("f".to_owned()), var("X"), var("Y")]);
+    let value = Token::List(vec![
+        Token::Identifier("f".to_owned()),
+        Token::Number(1.0),
+        Token::List(vec![atom("a"), atom("b")]),
+    ]);
+
+    let res = matches(&pattern, &value).unwrap();
+
+    assert_eq!(Some(&Token::Number(1.0)), res.get("X"));
+    assert_eq!(Some(&Token::List(vec![atom("a"), atom("b")])), res.get("Y"));
+}
+
+#[test]
+fn should_keep_pattern_and_value_variables_apart() {
+    let pattern = Token::List(vec![Token::Identifier("f".to_owned()), var("X"), var("Y")]);
+    let value = Token::List(vec![
+        Token::Identifier("f".to_owned()),
+        var("Y"),
+        Token::Number(1.0),
+    ]);
+
+    let res = matches(&pattern, &value).unwrap();
+
+    assert_eq!(Some(&var("Y")), res.get("X"));
+    assert_eq!(Some(&Token::Number(1.0)), res.get("Y"));
+}
+
+#[test]
+fn should_fail_occurs_check() {
+    let mut subst = Substitution::new();
+
+    let res = subst.unify(&var("X"), &Token::List(vec![atom("f"), var("X")]));
+
+    assert!(!res)
+}
+
+#[test]
+fn should_resolve_chained_bindings() {
+    let mut subst = Substitution::new();
+
+    assert!(subst.unify(&var("X"), &var("Y")));
+    assert!(subst.unify(&var("Y"), &atom("a")));
+    assert_eq!(
+        Token::List(vec![atom("a"), atom("a")]),
+        subst.resolve(Token::List(vec![var("X"), var("Y")]))
+    );
+}
+
+#[test]
+fn should_not_bind_keyword_argument_to_positional_variable() {
+    let call = |arg: Token| Token::List(vec![Token::Identifier("f".to_owned()), arg]);
+    let keyword = Token::KeywordArg("to".to_owned(), Box::new(atom("a")));
+
+    assert!(matches(&call(var("X")), &call(keyword.clone())).is_none());
+    assert!(matches(&call(keyword.clone()), &call(var("X"))).is_none());
+    assert!(matches(&call(atom("a")), &call(var("X"))).is_some());
+}
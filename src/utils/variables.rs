@@ -1,58 +1,79 @@
 use crate::grammar::token::Token;
 use crate::utils::posibility::Posibility;
+use crate::utils::unify;
 use std::collections::HashMap;
 
-pub fn fill_variable(source: Token, target: Token) -> Token {
-    match (source.clone(), target.clone()) {
-        (x, Token::Value) => x,
-        (Token::Document(doc, a), Token::Document(_, b)) => {
-            Token::Document(doc, Box::new(fill_variable(*a.clone(), *b.clone())))
-        }
-        (Token::Definition(a, b), Token::Definition(x, y)) => {
-            let a = *a.clone();
-            let x = *x.clone();
-            let b = *b.clone();
-            let y = *y.clone();
-
-            let def =
-                Token::Definition(Box::new(fill_variable(a, x)), Box::new(fill_variable(b, y)));
-
-            def
-        }
-        (Token::List(list1), Token::List(list2)) => {
-            let res = list1
-                .into_iter()
-                .zip(list2.into_iter())
-                .map(|(a, b)| fill_variable(a, b))
-                .collect();
-            Token::List(res)
-        }
-        (Token::Variable(var, Some(a)), Token::Variable(_, None)) => Token::Variable(var, Some(a)),
-        (Token::Variable(x, None), Token::Variable(y, None)) => Token::Variable(
-            y,
-            Some(Posibility::new().with_value(Token::Variable(x, None))),
-        ),
-        (a, Token::Variable(var, None)) => {
-            Token::Variable(var, Some(Posibility::new().with_value(a)))
-        }
-        _ => target,
+// `variables` without the names `pattern` binds again
+fn shadowed(variables: &HashMap<String, Token>, pattern: &Token) -> HashMap<String, Token> {
+    let mut res = variables.clone();
+    for name in unify::variables(pattern) {
+        res.remove(&name);
     }
-}
 
-pub fn extract_variables(source: Token) -> HashMap<String, Posibility<Token>> {
-    let mut res: HashMap<String, Posibility<Token>> = HashMap::new();
     res
 }
 
+// keyword of a form binding variables of its own, the names `@fn`
+// parameters and `@match` patterns bind are left alone within the form
+fn binder(list: &[Token]) -> Option<&str> {
+    match list.first().map(Token::inner) {
+        Some(Token::Keyword(key)) if key == "fn" || key == "match" => Some(key),
+        _ => None,
+    }
+}
+
 pub fn inject_variables(source: Token, variables: HashMap<String, Token>) -> Token {
     match source {
+        Token::List(list) if binder(&list) == Some("fn") && list.len() == 3 => {
+            let variables = shadowed(&variables, &list[1]);
+
+            Token::List(
+                list.into_iter()
+                    .map(|token| inject_variables(token, variables.clone()))
+                    .collect(),
+            )
+        }
+        Token::List(list) if binder(&list) == Some("match") && list.len() > 1 => {
+            let mut list = list.into_iter();
+            let mut res = vec![list.next().unwrap()];
+            res.extend(
+                list.next()
+                    .map(|value| inject_variables(value, variables.clone())),
+            );
+
+            for arm in list {
+                let variables = match arm.inner() {
+                    Token::List(pair) if pair.len() == 2 => shadowed(&variables, &pair[0]),
+                    _ => variables.clone(),
+                };
+                res.push(inject_variables(arm, variables));
+            }
+
+            Token::List(res)
+        }
         Token::List(list) => Token::List(
             list.into_iter()
                 .map(move |token| inject_variables(token, variables.clone()))
                 .collect(),
         ),
-        Token::Variable(s, _) => match variables.get(&s.clone()) {
-            None => Token::Variable(s, None),
+        Token::Spanned(span, token) => {
+            Token::Spanned(span, Box::new(inject_variables(*token, variables)))
+        }
+        Token::KeywordArg(name, value) => {
+            Token::KeywordArg(name, Box::new(inject_variables(*value, variables)))
+        }
+        // a signature binds its variables for the body
+        Token::Definition(sig, body) => {
+            let variables = shadowed(&variables, &sig);
+
+            Token::Definition(
+                Box::new(inject_variables(*sig, variables.clone())),
+                Box::new(inject_variables(*body, variables)),
+            )
+        }
+        // a name this `@let` does not bind keeps what an outer one gave it
+        Token::Variable(s, value) => match variables.get(&s.clone()) {
+            None => Token::Variable(s, value),
             Some(Token::Variable(key, val)) => Token::Variable(key.to_owned(), val.clone()),
             Some(val) => Token::Variable(s, Some(Posibility::new().with_value(val.clone()))),
         },